@@ -37,6 +37,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         })
     });
 
+    let kingdom_ident = extended_cards
+        .iter()
+        .filter(|card| card.kingdom)
+        .map(|card| Ident::new(&card.ident, Span::call_site()));
+
     let vp_method = victory_points_method(&extended_cards);
 
     let description = extended_cards.iter().map(|card| &card.card.description);
@@ -44,11 +49,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let resources = parse_description(&extended_cards);
 
     let tokens = quote! {
-        use serde::Deserialize;
+        use serde::{Deserialize, Serialize};
         use std::str::FromStr;
 
         #[allow(clippy::module_name_repetitions)]
-        #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
+        #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
         pub enum CardKind {
             #(#ident,)*
         }
@@ -64,6 +69,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         #[allow(dead_code)]
         #[allow(clippy::match_same_arms)]
         impl CardKind {
+            /// Every card that can be chosen as one of the kingdom cards.
+            pub const KINGDOM: &'static [CardKind] = &[#(CardKind::#kingdom_ident,)*];
+
             pub fn name(self) -> &'static str {
                 match self {
                     #(CardKind::#ident => #name,)*
@@ -120,6 +128,8 @@ const CARD_TYPES: &[&'static str] = &["Action", "Reaction", "Attack", "Victory",
 struct CardExt {
     card: Card,
     ident: String,
+    /// `false` for the base cards that are in every game.
+    kingdom: bool,
 }
 
 fn parse_cardset(
@@ -158,10 +168,10 @@ fn parse_cardset(
     }
 
     let mut extended_cards: Vec<_> = cards
-        .drain(std::ops::Range {
-            start: baseset_card_indices.first().unwrap(),
-            end: baseset_card_indices.last().unwrap(),
-        })
+        .drain(std::ops::RangeInclusive::new(
+            *baseset_card_indices.first().unwrap(),
+            *baseset_card_indices.last().unwrap(),
+        ))
         .map(|card| {
             let ident = if card.name.is_empty() {
                 missing_no += 1;
@@ -170,7 +180,11 @@ fn parse_cardset(
                 non_ident_regex.replace_all(&card.name, "").to_string()
             };
 
-            CardExt { card, ident }
+            CardExt {
+                card,
+                ident,
+                kingdom: true,
+            }
         })
         .collect();
 
@@ -276,15 +290,21 @@ fn base_cards(types: &Vec<String>) -> Vec<CardExt> {
         .map(|card| {
             let ident = card.name.clone();
 
-            CardExt { card, ident }
+            CardExt {
+                card,
+                ident,
+                kingdom: false,
+            }
         })
         .collect()
 }
 
 fn parse_description(cards: &Vec<CardExt>) -> TokenStream {
-    // Build resource regexs
-    let other_regex = Regex::new(r"\+(\d+) (Card|Action|Buy)").unwrap();
-    let copper_regex = Regex::new(r"\+\$(\d+)").unwrap();
+    // Build resource regexs. Resources are only listed at the start of a line,
+    // e.g. Merchant's "The first time you play a Silver this turn, +$1." is a
+    // conditional effect and not a resource.
+    let other_regex = Regex::new(r"^\+(\d+) (Card|Action|Buy)").unwrap();
+    let copper_regex = Regex::new(r"^\+\$(\d+)").unwrap();
 
     let desc_type_delim = "———-";
 
@@ -0,0 +1,370 @@
+//! Plays many games between agents without any human players and reports how
+//! well each agent did.
+//!
+//! Usage: simulate [--games <n>] [--seed <seed>] [--kingdom <set>] [--threads <n>] <agent>...
+
+use dominion::dominion::agent::{self, AGENT_NAMES};
+use dominion::dominion::config::{MAX_PLAYERS, MIN_PLAYERS};
+use dominion::{play_game, Arena, KingdomSet};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+use std::thread;
+
+/// Critical value of the normal distribution for a 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+struct Config {
+    games: u64,
+    seed: u64,
+    kingdom: KingdomSet,
+    threads: u64,
+    agents: Vec<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Outcome {
+    Win,
+    Tie,
+    Loss,
+}
+
+/// Result of a single game, ordered by the configured agents rather than by
+/// seat.
+#[derive(Debug, PartialEq)]
+struct GameRecord {
+    outcomes: Vec<(Outcome, i32)>,
+    /// Number of turns taken by the player that took the most turns.
+    turns: u32,
+}
+
+/// Mean of a sample and the half width of its 95% confidence interval.
+#[derive(Debug, PartialEq)]
+struct Estimate {
+    mean: f64,
+    margin: f64,
+}
+
+impl Estimate {
+    fn new(samples: impl Iterator<Item = f64> + Clone) -> Self {
+        let n = samples.clone().count() as f64;
+        let mean = samples.clone().sum::<f64>() / n;
+        let variance = if n > 1.0 {
+            samples.map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            mean,
+            margin: Z_95 * (variance / n).sqrt(),
+        }
+    }
+}
+
+/// A proportion and its 95% Wilson score interval, which stays within 0 and 1
+/// and has a nonzero width even when every sample is a success or a failure.
+#[derive(Debug, PartialEq)]
+struct Rate {
+    mean: f64,
+    low: f64,
+    high: f64,
+}
+
+impl Rate {
+    fn new(successes: usize, n: usize) -> Self {
+        let n = n as f64;
+        let mean = successes as f64 / n;
+        let z2 = Z_95 * Z_95;
+
+        let center = (mean + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let margin = Z_95 / (1.0 + z2 / n) * (mean * (1.0 - mean) / n + z2 / (4.0 * n * n)).sqrt();
+
+        Self {
+            mean,
+            low: (center - margin).max(0.0),
+            high: (center + margin).min(1.0),
+        }
+    }
+}
+
+fn main() {
+    let config = parse_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        eprintln!(
+            "Usage: simulate [--games <n>] [--seed <seed>] [--kingdom <set>] [--threads <n>] <agent>...\n\
+             Agents: {}\n\
+             Kingdom sets: {}",
+            AGENT_NAMES.join(", "),
+            KingdomSet::ALL
+                .iter()
+                .map(|set| format!("\"{}\"", set.name()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        std::process::exit(2);
+    });
+
+    let records = run(&config).unwrap_or_else(|e| {
+        eprintln!("Game failed: {}", e);
+        std::process::exit(1);
+    });
+
+    report(&config, &records);
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Config, String> {
+    let mut config = Config {
+        games: 1000,
+        seed: 0,
+        kingdom: KingdomSet::FirstGame,
+        threads: 1,
+        agents: Vec::new(),
+    };
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+
+        match arg.as_str() {
+            "--games" => config.games = parse_number("--games", &value("--games")?)?,
+            "--seed" => config.seed = parse_number("--seed", &value("--seed")?)?,
+            "--threads" => config.threads = parse_number("--threads", &value("--threads")?)?,
+            "--kingdom" => {
+                let name = value("--kingdom")?;
+                config.kingdom = name
+                    .parse()
+                    .map_err(|_| format!("Unknown kingdom set {}", name))?;
+            }
+            name if agent::by_name(name).is_some() => config.agents.push(name.to_string()),
+            _ => return Err(format!("Unknown agent or option {}", arg)),
+        }
+    }
+
+    if (config.agents.len() < MIN_PLAYERS) || (config.agents.len() > MAX_PLAYERS) {
+        Err(format!(
+            "From {} to {} agents are required",
+            MIN_PLAYERS, MAX_PLAYERS
+        ))
+    } else if (config.games == 0) || (config.threads == 0) {
+        Err("The number of games and threads must be positive".to_string())
+    } else {
+        Ok(config)
+    }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value {} for {}", value, name))
+}
+
+/// Plays every game, splitting them evenly across threads. Game `i` uses seed
+/// `config.seed + i` and rotates the seats so that each agent goes first
+/// equally often.
+fn run(config: &Config) -> Result<Vec<GameRecord>, String> {
+    let handles: Vec<_> = (0..config.threads)
+        .map(|thread_id| {
+            let games: Vec<_> = (thread_id..config.games)
+                .step_by(config.threads as usize)
+                .collect();
+            let (seed, kingdom, agents) = (config.seed, config.kingdom, config.agents.clone());
+
+            thread::spawn(move || {
+                games
+                    .into_iter()
+                    .map(|game| {
+                        play(seed.wrapping_add(game), kingdom, &agents, game as usize)
+                            .map(|record| (game, record))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+        })
+        .collect();
+
+    let mut records = Vec::new();
+    for handle in handles {
+        records.append(&mut handle.join().map_err(|_| "thread panicked".to_string())??);
+    }
+    records.sort_by_key(|&(game, _)| game);
+
+    Ok(records.into_iter().map(|(_, record)| record).collect())
+}
+
+fn play(
+    seed: u64,
+    kingdom: KingdomSet,
+    agents: &[String],
+    rotation: usize,
+) -> Result<GameRecord, String> {
+    let n = agents.len();
+    let agent_id = |seat: usize| (seat + rotation) % n;
+
+    let mut arena = Arena::with_kingdom(&kingdom.cards(), n, Pcg64Mcg::seed_from_u64(seed))
+        .map_err(|e| format!("{:?} with seed {}", e, seed))?;
+    let mut seats: Vec<_> = (0..n)
+        .map(|seat| agent::by_name(&agents[agent_id(seat)]).unwrap())
+        .collect();
+
+    let result =
+        play_game(&mut arena, &mut seats).map_err(|e| format!("{:?} with seed {}", e, seed))?;
+    let winners = result.winners().count();
+
+    let mut outcomes = vec![(Outcome::Loss, 0); n];
+    for standing in &result.standings {
+        let outcome = match (standing.place, winners) {
+            (1, 1) => Outcome::Win,
+            (1, _) => Outcome::Tie,
+            _ => Outcome::Loss,
+        };
+
+        outcomes[agent_id(standing.score.player_id)] = (outcome, standing.score.victory_points);
+    }
+
+    Ok(GameRecord {
+        outcomes,
+        turns: result
+            .standings
+            .iter()
+            .map(|standing| standing.score.turns)
+            .max()
+            .unwrap(),
+    })
+}
+
+fn report(config: &Config, records: &[GameRecord]) {
+    println!(
+        "Played {} games on {} with seeds starting at {}\n",
+        records.len(),
+        config.kingdom.name(),
+        config.seed
+    );
+    println!(
+        "{:<20} {:>20} {:>20} {:>20} {:>16}",
+        "agent", "win", "tie", "loss", "victory points"
+    );
+
+    for (id, name) in config.agents.iter().enumerate() {
+        let rate = |outcome: Outcome| {
+            let successes = records
+                .iter()
+                .filter(|record| record.outcomes[id].0 == outcome)
+                .count();
+            let rate = Rate::new(successes, records.len());
+            format!(
+                "{:.1}% ({:.1}-{:.1})",
+                100.0 * rate.mean,
+                100.0 * rate.low,
+                100.0 * rate.high
+            )
+        };
+        let vp = Estimate::new(
+            records
+                .iter()
+                .map(|record| f64::from(record.outcomes[id].1)),
+        );
+
+        println!(
+            "{:<20} {:>20} {:>20} {:>20} {:>16}",
+            name,
+            rate(Outcome::Win),
+            rate(Outcome::Tie),
+            rate(Outcome::Loss),
+            format!("{:.1} ± {:.1}", vp.mean, vp.margin)
+        );
+    }
+
+    let turns = Estimate::new(records.iter().map(|record| f64::from(record.turns)));
+    println!(
+        "\nAverage game length: {:.1} ± {:.1} turns",
+        turns.mean, turns.margin
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> {
+        s.split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn parse_options() {
+        let config = parse_args(args(
+            "--games 10 --seed 3 --threads 2 big-money big-money-smithy",
+        ))
+        .unwrap();
+
+        assert_eq!(config.games, 10);
+        assert_eq!(config.seed, 3);
+        assert_eq!(config.threads, 2);
+        assert_eq!(config.kingdom, KingdomSet::FirstGame);
+        assert_eq!(config.agents, vec!["big-money", "big-money-smithy"]);
+    }
+
+    #[test]
+    fn parse_invalid_options() {
+        assert!(parse_args(args("big-money")).is_err());
+        assert!(parse_args(args("big-money random")).is_err());
+        assert!(parse_args(args(&vec!["big-money"; 7].join(" "))).is_err());
+        assert!(parse_args(args("--games big-money big-money")).is_err());
+        assert!(parse_args(args("--threads 0 big-money big-money")).is_err());
+        assert!(parse_args(args("--kingdom Unknown big-money big-money")).is_err());
+        assert!(parse_args(args("--kingdom DeckTop big-money big-money")).is_ok());
+
+        let config = parse_args(
+            vec!["--kingdom", "Deck Top", "big-money", "big-money"]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+        assert_eq!(config.kingdom, KingdomSet::DeckTop);
+    }
+
+    #[test]
+    fn estimate_of_constant_sample() {
+        assert_eq!(
+            Estimate::new(vec![2.0, 2.0, 2.0].into_iter()),
+            Estimate {
+                mean: 2.0,
+                margin: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn rate_interval_is_never_empty() {
+        let none = Rate::new(0, 1);
+        assert_eq!(none.mean, 0.0);
+        assert_eq!(none.low, 0.0);
+        assert!(none.high > 0.5);
+
+        let all = Rate::new(10, 10);
+        assert_eq!(all.mean, 1.0);
+        assert!(all.low < 1.0);
+        assert_eq!(all.high, 1.0);
+    }
+
+    #[test]
+    fn rate_interval_contains_mean() {
+        let rate = Rate::new(30, 100);
+        assert!(rate.low < 0.3 && 0.3 < rate.high);
+        assert!((rate.low - 0.219).abs() < 0.001);
+        assert!((rate.high - 0.396).abs() < 0.001);
+    }
+
+    #[test]
+    fn games_are_independent_of_thread_count() {
+        let mut config = parse_args(args("--games 6 big-money big-money-smithy")).unwrap();
+        let one = run(&config).unwrap();
+
+        config.threads = 4;
+        assert_eq!(run(&config).unwrap(), one);
+    }
+}
@@ -3,11 +3,19 @@
 // cardvec! macro defined in location is used in arena::tests so location must
 // be declared first.
 pub mod location;
+pub mod agent;
 pub mod arena;
 pub mod card {
     include!(concat!(env!("OUT_DIR"), "/card.rs"));
 }
+pub mod config;
+pub mod decision;
+pub mod event;
 pub mod kingdom;
+pub mod observation;
+pub mod replay;
+pub mod saved_game;
+pub mod score;
 pub mod turn;
 pub mod types;
 
@@ -0,0 +1,302 @@
+use crate::dominion::decision::Decision;
+use crate::dominion::observation::Observation;
+use crate::dominion::score::GameResult;
+use crate::dominion::types::{Error, Move, Result};
+use crate::dominion::{Arena, CardKind};
+use rand::Rng;
+
+mod big_money;
+mod mcts;
+use self::big_money::play_treasure;
+pub use self::big_money::{BigMoney, BigMoneySmithy};
+pub use self::mcts::Mcts;
+
+/// A player that makes its own moves.
+pub trait Agent {
+    /// Returns the next move to make. `decision` is the decision that must be
+    /// made before the game can continue, if any, and `moves` are the legal
+    /// moves of the observing player. `moves` is never empty.
+    fn choose(
+        &mut self,
+        observation: &Observation,
+        decision: Option<&Decision>,
+        moves: &[Move],
+    ) -> Move;
+
+    /// Returns the next move to make given a view of the whole game. Agents
+    /// that search ahead can sample the hidden cards from `view`. By default,
+    /// only the observation is used.
+    fn choose_from(&mut self, view: &PlayerView, moves: &[Move]) -> Move {
+        self.choose(&view.observation(), view.pending_decision(), moves)
+    }
+}
+
+/// A game as seen by a single player. Cards hidden from the player are only
+/// available through random samples.
+pub struct PlayerView<'a> {
+    arena: &'a Arena,
+    player_id: usize,
+}
+
+impl<'a> PlayerView<'a> {
+    pub fn new(arena: &'a Arena, player_id: usize) -> Result<Self> {
+        arena.observe(player_id)?;
+
+        Ok(Self { arena, player_id })
+    }
+
+    pub fn player_id(&self) -> usize {
+        self.player_id
+    }
+
+    pub fn observation(&self) -> Observation {
+        self.arena.observe(self.player_id).unwrap()
+    }
+
+    pub fn pending_decision(&self) -> Option<&'static Decision> {
+        self.arena.pending_decision()
+    }
+
+    /// Returns a copy of the game with the hidden cards chosen at random. See
+    /// `Arena::determinize`.
+    pub fn sample(&self, rng: &mut impl Rng) -> Arena {
+        self.arena.determinize(self.player_id, rng).unwrap()
+    }
+}
+
+/// Names of the built-in agents accepted by `by_name`.
+pub const AGENT_NAMES: &[&str] = &["big-money", "big-money-smithy", "mcts"];
+
+/// Returns a new built-in agent or `None` if there is no agent named `name`.
+pub fn by_name(name: &str) -> Option<Box<dyn Agent>> {
+    match name {
+        "big-money" => Some(Box::new(BigMoney::new())),
+        "big-money-smithy" => Some(Box::new(BigMoneySmithy::new())),
+        "mcts" => Some(Box::new(Mcts::new())),
+        _ => None,
+    }
+}
+
+/// Number of turns a player can take before `play_game` gives up on a game
+/// that is not ending, such as one where no agent buys Victory cards.
+#[allow(dead_code)]
+pub const MAX_TURNS: u32 = 200;
+
+/// Plays `arena` until the game is over, asking the agent seated at each
+/// player id for that player's moves.
+///
+/// # Errors
+///
+/// If there are fewer agents than players, then `Error::InvalidPlayerId` is
+/// returned. If an agent chooses a move that is not legal, then
+/// `Error::IllegalMove` is returned. If a player would take more than
+/// `MAX_TURNS` turns, then `Error::TooManyTurns` is returned.
+#[allow(dead_code)]
+pub fn play_game(arena: &mut Arena, agents: &mut [Box<dyn Agent>]) -> Result<GameResult> {
+    let num_players = arena.scores().len();
+
+    if agents.len() < num_players {
+        return Err(Error::InvalidPlayerId);
+    }
+
+    while let Some((player_id, moves)) = next_to_move(arena) {
+        if arena.scores().iter().any(|score| score.turns > MAX_TURNS) {
+            return Err(Error::TooManyTurns);
+        }
+
+        let mv = agents[player_id].choose_from(&PlayerView::new(arena, player_id)?, &moves);
+
+        if !moves.contains(&mv) {
+            return Err(Error::IllegalMove);
+        }
+
+        make_move(arena, player_id, &mv)?;
+    }
+
+    Ok(arena.result().unwrap())
+}
+
+/// Returns the player that must move next and their legal moves, or `None` if
+/// the game is over. Several players may have to choose while a decision is
+/// pending, in which case they choose in order of player id.
+pub fn next_to_move(arena: &Arena) -> Option<(usize, Vec<Move>)> {
+    if arena.is_game_over() {
+        return None;
+    }
+
+    (0..arena.scores().len())
+        .map(|player_id| (player_id, arena.legal_moves(player_id).unwrap()))
+        .find(|(_, moves)| !moves.is_empty())
+}
+
+/// Makes a legal move, ignoring the error returned when playing an Action card
+/// or selecting cards leaves a decision pending.
+fn make_move(arena: &mut Arena, player_id: usize, mv: &Move) -> Result<()> {
+    match (mv, arena.make_move(player_id, mv)) {
+        (Move::Play(_), Err(Error::UnresolvedActionEffect(_)))
+        | (Move::Select(_), Err(Error::UnresolvedActionEffect(_))) => Ok(()),
+        (_, result) => result,
+    }
+}
+
+/// Returns the selection that gives up the least valuable cards, for
+/// decisions such as discarding or trashing cards. If `decision` is optional,
+/// a non-empty selection is preferred, such as revealing a Reaction.
+pub fn cheapest_selection(decision: &Decision, moves: &[Move]) -> Move {
+    fn worth(card: CardKind) -> u32 {
+        if card.is_victory() || (card == CardKind::Curse) {
+            0
+        } else {
+            1 + u32::from(card.cost())
+        }
+    }
+
+    moves
+        .iter()
+        .min_by_key(|mv| match mv {
+            Move::Select(cards) => (
+                decision.optional && cards.is_empty(),
+                cards.iter().map(|&card| worth(card)).sum::<u32>(),
+            ),
+            _ => (true, u32::max_value()),
+        })
+        .cloned()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::decision::{CardCount, CardFilter, Chooser, Zone};
+    use crate::dominion::KingdomSet;
+
+    #[test]
+    fn cheapest_selection_discards_victory_cards() {
+        let decision = Decision {
+            description: "Discard down to 3 cards in hand.",
+            chooser: Chooser::OtherPlayers,
+            zone: Zone::Hand,
+            count: CardCount::DownTo(3),
+            filter: CardFilter::Any,
+            max_cost: None,
+            optional: false,
+        };
+        let moves = vec![
+            Move::Select(vec![CardKind::Gold, CardKind::Estate]),
+            Move::Select(vec![CardKind::Estate, CardKind::Duchy]),
+            Move::Select(vec![CardKind::Copper, CardKind::Duchy]),
+        ];
+
+        assert_eq!(
+            cheapest_selection(&decision, &moves),
+            Move::Select(vec![CardKind::Estate, CardKind::Duchy])
+        );
+    }
+
+    #[test]
+    fn cheapest_selection_reveals_when_optional() {
+        let decision = Decision {
+            description: "Reveal a Reaction card.",
+            chooser: Chooser::OtherPlayers,
+            zone: Zone::Hand,
+            count: CardCount::Exactly(1),
+            filter: CardFilter::Reaction,
+            max_cost: None,
+            optional: true,
+        };
+        let moves = vec![Move::Select(vec![]), Move::Select(vec![CardKind::Moat])];
+
+        assert_eq!(
+            cheapest_selection(&decision, &moves),
+            Move::Select(vec![CardKind::Moat])
+        );
+    }
+
+    #[test]
+    fn play_game_to_completion() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut agents: Vec<Box<dyn Agent>> =
+            vec![Box::new(BigMoney::new()), Box::new(BigMoneySmithy::new())];
+
+        let result = play_game(&mut arena, &mut agents).unwrap();
+
+        assert!(arena.is_game_over());
+        assert_eq!(result.standings.len(), 2);
+    }
+
+    #[test]
+    fn play_game_with_six_players() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 6, 1);
+        let mut agents: Vec<Box<dyn Agent>> = (0..6)
+            .map(|_| Box::new(BigMoney::new()) as Box<dyn Agent>)
+            .collect();
+
+        let result = play_game(&mut arena, &mut agents).unwrap();
+
+        assert!(arena.is_game_over());
+        assert_eq!(result.standings.len(), 6);
+    }
+
+    /// Ends every phase without buying anything.
+    struct Passive;
+
+    impl Agent for Passive {
+        fn choose(&mut self, _: &Observation, _: Option<&Decision>, moves: &[Move]) -> Move {
+            moves
+                .iter()
+                .find(|&mv| *mv == Move::EndPhase)
+                .unwrap_or(&moves[0])
+                .clone()
+        }
+    }
+
+    #[test]
+    fn play_game_stops_after_too_many_turns() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut agents: Vec<Box<dyn Agent>> = vec![Box::new(Passive), Box::new(Passive)];
+
+        assert_eq!(
+            play_game(&mut arena, &mut agents).map(|_| ()),
+            Err(Error::TooManyTurns)
+        );
+        assert!(!arena.is_game_over());
+    }
+
+    #[test]
+    fn play_game_with_too_few_agents() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut agents: Vec<Box<dyn Agent>> = vec![Box::new(BigMoney::new())];
+
+        assert_eq!(
+            play_game(&mut arena, &mut agents).map(|_| ()),
+            Err(Error::InvalidPlayerId)
+        );
+    }
+
+    #[test]
+    fn every_agent_name_is_built_in() {
+        for name in AGENT_NAMES {
+            assert!(by_name(name).is_some(), "no agent named {}", name);
+        }
+        assert!(by_name("random").is_none());
+    }
+
+    struct Stubborn;
+
+    impl Agent for Stubborn {
+        fn choose(&mut self, _: &Observation, _: Option<&Decision>, _: &[Move]) -> Move {
+            Move::Buy(CardKind::Province)
+        }
+    }
+
+    #[test]
+    fn play_game_with_illegal_move() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut agents: Vec<Box<dyn Agent>> = vec![Box::new(Stubborn), Box::new(Stubborn)];
+
+        assert_eq!(
+            play_game(&mut arena, &mut agents).map(|_| ()),
+            Err(Error::IllegalMove)
+        );
+    }
+}
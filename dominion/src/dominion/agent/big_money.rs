@@ -0,0 +1,159 @@
+use super::{cheapest_selection, Agent};
+use crate::dominion::decision::Decision;
+use crate::dominion::observation::Observation;
+use crate::dominion::turn::Turn;
+use crate::dominion::types::Move;
+use crate::dominion::CardKind;
+
+/// Plays every treasure and buys the most expensive of Province, Gold and
+/// Silver that it can afford. Never plays Action cards.
+#[derive(Debug, Default)]
+pub struct BigMoney;
+
+/// Big Money that also buys a Smithy for every 11 cards in its deck and plays
+/// it whenever it can.
+#[derive(Debug, Default)]
+pub struct BigMoneySmithy {
+    smithies: usize,
+}
+
+impl BigMoney {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl BigMoneySmithy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Agent for BigMoney {
+    fn choose(
+        &mut self,
+        observation: &Observation,
+        decision: Option<&Decision>,
+        moves: &[Move],
+    ) -> Move {
+        if let Some(decision) = decision {
+            return cheapest_selection(decision, moves);
+        }
+
+        match observation.turn {
+            Turn::Action(_) => Move::EndPhase,
+            Turn::Buy(buy_phase) => play_treasure(moves)
+                .or_else(|| buy(moves, money(buy_phase.remaining_copper)))
+                .unwrap_or(Move::EndPhase),
+        }
+    }
+}
+
+impl Agent for BigMoneySmithy {
+    fn choose(
+        &mut self,
+        observation: &Observation,
+        decision: Option<&Decision>,
+        moves: &[Move],
+    ) -> Move {
+        if let Some(decision) = decision {
+            return cheapest_selection(decision, moves);
+        }
+
+        match observation.turn {
+            Turn::Action(_) => {
+                let smithy = Move::Play(CardKind::Smithy);
+
+                if moves.contains(&smithy) {
+                    smithy
+                } else {
+                    Move::EndPhase
+                }
+            }
+            Turn::Buy(buy_phase) => {
+                if let Some(mv) = play_treasure(moves) {
+                    return mv;
+                }
+
+                let copper = buy_phase.remaining_copper;
+                let player = &observation.players[observation.player_id];
+                let deck_size = player.hand_size
+                    + player.draw_pile_size
+                    + player.discard_pile_size
+                    + player.play_zone.len()
+                    + player.stage_size;
+
+                let smithy = if (4..6).contains(&copper) && (self.smithies * 11 < deck_size) {
+                    buy(moves, Some(CardKind::Smithy))
+                } else {
+                    None
+                };
+
+                if smithy.is_some() {
+                    self.smithies += 1;
+                }
+
+                smithy
+                    .or_else(|| buy(moves, money(copper)))
+                    .unwrap_or(Move::EndPhase)
+            }
+        }
+    }
+}
+
+/// Returns a move playing any treasure in hand. Only treasures can be played
+/// during the buy phase.
+pub(super) fn play_treasure(moves: &[Move]) -> Option<Move> {
+    moves
+        .iter()
+        .find(|mv| if let Move::Play(_) = mv { true } else { false })
+        .cloned()
+}
+
+/// Returns the card bought by Big Money with `copper` to spend.
+fn money(copper: u8) -> Option<CardKind> {
+    match copper {
+        0..=2 => None,
+        3..=5 => Some(CardKind::Silver),
+        6..=7 => Some(CardKind::Gold),
+        _ => Some(CardKind::Province),
+    }
+}
+
+fn buy(moves: &[Move], card: Option<CardKind>) -> Option<Move> {
+    card.map(Move::Buy).filter(|mv| moves.contains(mv))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::agent::play_game;
+    use crate::dominion::{Arena, KingdomSet};
+
+    #[test]
+    fn money_buys() {
+        assert_eq!(money(2), None);
+        assert_eq!(money(3), Some(CardKind::Silver));
+        assert_eq!(money(5), Some(CardKind::Silver));
+        assert_eq!(money(6), Some(CardKind::Gold));
+        assert_eq!(money(8), Some(CardKind::Province));
+        assert_eq!(money(11), Some(CardKind::Province));
+    }
+
+    #[test]
+    fn big_money_smithy_beats_big_money() {
+        let games = 20;
+        let wins = (0..games)
+            .filter(|&seed| {
+                let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, seed);
+                let mut agents: Vec<Box<dyn Agent>> =
+                    vec![Box::new(BigMoney::new()), Box::new(BigMoneySmithy::new())];
+
+                let result = play_game(&mut arena, &mut agents).unwrap();
+                result.winners().eq(vec![1])
+            })
+            .count();
+
+        assert!(wins > games as usize / 2, "won {} of {} games", wins, games);
+    }
+}
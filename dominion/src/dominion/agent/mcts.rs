@@ -0,0 +1,308 @@
+use super::{make_move, next_to_move, play_treasure, Agent, BigMoney, PlayerView};
+use crate::dominion::decision::Decision;
+use crate::dominion::observation::Observation;
+use crate::dominion::score::Score;
+use crate::dominion::turn::Turn;
+use crate::dominion::types::Move;
+use crate::dominion::Arena;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+
+/// Number of playouts per move made by `Mcts::new`.
+const DEFAULT_ITERATIONS: usize = 500;
+
+/// Exploration constant of UCB1. Rewards are between 0 and 1.
+const EXPLORATION: f64 = 0.7;
+
+/// Moves after which a playout is stopped and scored as it stands, in case
+/// the playout agents never end the game.
+const MAX_PLAYOUT_MOVES: usize = 2000;
+
+/// Scale of the lead in victory points used as a reward. A lead gives a
+/// stronger signal than whether a playout was won.
+const VICTORY_POINT_SCALE: f64 = 20.0;
+
+/// Chooses moves with Information Set Monte Carlo Tree Search. Every
+/// iteration samples the cards hidden from the player, walks the search tree
+/// using only moves that are legal in the sample and finishes the game with
+/// Big Money playouts for every player.
+#[derive(Debug)]
+pub struct Mcts {
+    iterations: usize,
+    rng: Pcg64Mcg,
+}
+
+/// A move in the search tree. The root has no move.
+#[derive(Debug)]
+struct Node {
+    mv: Option<Move>,
+    /// Player that made `mv`.
+    player_id: usize,
+    children: Vec<usize>,
+    visits: u32,
+    /// Number of times the node could have been selected.
+    availability: u32,
+    /// Total reward of `player_id` over every visit.
+    reward: f64,
+}
+
+impl Node {
+    fn new(mv: Option<Move>, player_id: usize) -> Self {
+        Self {
+            mv,
+            player_id,
+            children: Vec::new(),
+            visits: 0,
+            availability: 0,
+            reward: 0.0,
+        }
+    }
+
+    fn ucb(&self) -> f64 {
+        let visits = f64::from(self.visits);
+
+        (self.reward / visits) + EXPLORATION * (f64::from(self.availability).ln() / visits).sqrt()
+    }
+}
+
+impl Mcts {
+    /// Returns an agent that runs the default number of iterations with a
+    /// fixed seed, so that games with the same arena seed play out the same.
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_ITERATIONS, 0)
+    }
+
+    pub fn with_seed(iterations: usize, seed: u64) -> Self {
+        Self {
+            iterations,
+            rng: Pcg64Mcg::seed_from_u64(seed),
+        }
+    }
+
+    /// Runs one iteration from the root of `tree` on a sample of the game.
+    fn iterate(&mut self, tree: &mut Vec<Node>, view: &PlayerView) {
+        let mut arena = view.sample(&mut self.rng);
+        let mut path = vec![0];
+
+        while let Some((player_id, moves)) = next_to_move(&arena) {
+            if let Some(mv) = forced_move(&arena, &moves) {
+                if make_move(&mut arena, player_id, &mv).is_err() {
+                    break;
+                }
+                continue;
+            }
+
+            let node = *path.last().unwrap();
+            let available: Vec<_> = tree[node]
+                .children
+                .iter()
+                .cloned()
+                .filter(|&child| {
+                    (tree[child].player_id == player_id)
+                        && moves.contains(tree[child].mv.as_ref().unwrap())
+                })
+                .collect();
+            let untried: Vec<_> = moves
+                .iter()
+                .filter(|&mv| {
+                    available
+                        .iter()
+                        .all(|&child| tree[child].mv.as_ref() != Some(mv))
+                })
+                .collect();
+
+            for &child in &available {
+                tree[child].availability += 1;
+            }
+
+            let (child, expanded) = match untried.choose(&mut self.rng) {
+                Some(&mv) => {
+                    let mut node = Node::new(Some(mv.clone()), player_id);
+                    node.availability = 1;
+                    tree.push(node);
+
+                    let child = tree.len() - 1;
+                    tree[*path.last().unwrap()].children.push(child);
+
+                    (child, true)
+                }
+                None => {
+                    let child = available
+                        .iter()
+                        .cloned()
+                        .max_by(|&a, &b| tree[a].ucb().partial_cmp(&tree[b].ucb()).unwrap())
+                        .unwrap();
+
+                    (child, false)
+                }
+            };
+
+            path.push(child);
+
+            if make_move(&mut arena, player_id, tree[child].mv.as_ref().unwrap()).is_err()
+                || expanded
+            {
+                break;
+            }
+        }
+
+        let rewards = playout(arena);
+
+        for node in path {
+            tree[node].visits += 1;
+            tree[node].reward += rewards[tree[node].player_id];
+        }
+    }
+}
+
+impl Default for Mcts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Agent for Mcts {
+    /// Without a view of the game there is nothing to search, so Big Money's
+    /// move is made instead.
+    fn choose(
+        &mut self,
+        observation: &Observation,
+        decision: Option<&Decision>,
+        moves: &[Move],
+    ) -> Move {
+        BigMoney::new().choose(observation, decision, moves)
+    }
+
+    fn choose_from(&mut self, view: &PlayerView, moves: &[Move]) -> Move {
+        if let Some(mv) = forced_move(view.arena, moves) {
+            return mv;
+        }
+
+        let mut tree = vec![Node::new(None, view.player_id())];
+
+        for _ in 0..self.iterations {
+            self.iterate(&mut tree, view);
+        }
+
+        tree[0]
+            .children
+            .iter()
+            .map(|&child| &tree[child])
+            .filter(|node| {
+                (node.player_id == view.player_id()) && moves.contains(node.mv.as_ref().unwrap())
+            })
+            .max_by_key(|node| node.visits)
+            .and_then(|node| node.mv.clone())
+            .unwrap_or_else(|| self.choose(&view.observation(), view.pending_decision(), moves))
+    }
+}
+
+/// Returns the move to make without searching, if there is one. Playing a
+/// treasure is never worse than keeping it in hand, so treasures are played
+/// as soon as the buy phase starts.
+fn forced_move(arena: &Arena, moves: &[Move]) -> Option<Move> {
+    if moves.len() == 1 {
+        return Some(moves[0].clone());
+    }
+
+    match (arena.pending_decision(), arena.turn()) {
+        (None, Turn::Buy(_)) => play_treasure(moves),
+        _ => None,
+    }
+}
+
+/// Plays `arena` with Big Money for every player and returns the reward of
+/// each player, ordered by player id.
+fn playout(mut arena: Arena) -> Vec<f64> {
+    let mut agent = BigMoney::new();
+
+    for _ in 0..MAX_PLAYOUT_MOVES {
+        let (player_id, moves) = match next_to_move(&arena) {
+            Some(next) => next,
+            None => break,
+        };
+        let mv = agent.choose_from(&PlayerView::new(&arena, player_id).unwrap(), &moves);
+
+        if make_move(&mut arena, player_id, &mv).is_err() {
+            break;
+        }
+    }
+
+    rewards(&arena.scores())
+}
+
+/// Returns the reward of each player, which grows from 0 to 1 with the lead
+/// over the best of the other players. Ties are worth 1/2.
+fn rewards(scores: &[Score]) -> Vec<f64> {
+    scores
+        .iter()
+        .map(|score| {
+            let best_other = scores
+                .iter()
+                .filter(|other| other.player_id != score.player_id)
+                .map(|other| other.victory_points)
+                .max()
+                .unwrap_or(0);
+            let lead = f64::from(score.victory_points - best_other);
+
+            (1.0 + (lead / VICTORY_POINT_SCALE).tanh()) / 2.0
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::agent::play_game;
+    use crate::dominion::KingdomSet;
+
+    #[test]
+    fn rewards_grow_with_lead() {
+        let score = |player_id, victory_points| Score {
+            player_id,
+            victory_points,
+            turns: 10,
+        };
+        let tied = rewards(&[score(0, 5), score(1, 8), score(2, 8)]);
+
+        assert_eq!(tied[1], 0.5);
+        assert_eq!(tied[2], 0.5);
+        assert!(tied[0] > 0.0 && tied[0] < 0.5);
+        assert!(rewards(&[score(0, 10), score(1, 2)])[0] > rewards(&[score(0, 4), score(1, 2)])[0]);
+    }
+
+    #[test]
+    fn choose_legal_move() {
+        let arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let moves = arena.legal_moves(0).unwrap();
+        let mut agent = Mcts::with_seed(20, 1);
+
+        let mv = agent.choose_from(&PlayerView::new(&arena, 0).unwrap(), &moves);
+
+        assert!(moves.contains(&mv), "{:?} is not legal", mv);
+    }
+
+    #[test]
+    fn same_seed_chooses_same_move() {
+        let arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let view = PlayerView::new(&arena, 0).unwrap();
+        let moves = arena.legal_moves(0).unwrap();
+
+        assert_eq!(
+            Mcts::with_seed(20, 3).choose_from(&view, &moves),
+            Mcts::with_seed(20, 3).choose_from(&view, &moves)
+        );
+    }
+
+    #[test]
+    fn play_game_against_big_money() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut agents: Vec<Box<dyn Agent>> =
+            vec![Box::new(Mcts::with_seed(2, 1)), Box::new(BigMoney::new())];
+
+        let result = play_game(&mut arena, &mut agents).unwrap();
+
+        assert_eq!(result.standings.len(), 2);
+    }
+}
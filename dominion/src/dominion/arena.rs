@@ -1,7 +1,16 @@
+use crate::dominion::config::{ConfigError, GameConfig, PileConfig};
+use crate::dominion::decision::Decision;
+use crate::dominion::event::GameEvent;
+use crate::dominion::kingdom::Kingdom;
 use crate::dominion::location::{CardVec, Location};
+use crate::dominion::observation::{Observation, PlayerObservation};
+use crate::dominion::score::{GameResult, Score};
 use crate::dominion::turn::{self, Turn};
-use crate::dominion::types::{Error, Result};
+use crate::dominion::types::{self, Error, LocationView, Result};
 use crate::dominion::{CardKind, KingdomSet};
+use rand::{FromEntropy, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+use serde::{Deserialize, Serialize};
 
 // These declarative macros are used to borrow a single player from the arena struct.
 // This is useful when moving cards between the supply/trash and a player. Using
@@ -17,47 +26,248 @@ macro_rules! current_player {
 //    ($x:ident) => { $x.players[$x.next_player_id()] }
 //}
 
+mod determinize;
 mod effect;
+mod legal;
+mod log;
 mod player;
+mod save;
 mod supply;
+mod trigger;
+mod undo;
+mod victory;
 use self::effect::CardActionQueue;
+use self::log::EventLog;
 use self::player::Player;
+pub(crate) use self::supply::invalid_pile;
 use self::supply::Supply;
+use self::trigger::Trigger;
+use self::undo::UndoHistory;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Arena {
     supply: Supply,
     trash: CardVec,
     players: Vec<Player>,
     turn: Turn,
     current_player_id: usize,
-    actions: Option<CardActionQueue>,
+    actions: CardActionQueue,
+    #[serde(with = "save::static_refs")]
+    triggers: Vec<&'static Trigger>,
+    rng: Pcg64Mcg,
+    log: EventLog,
+    #[serde(skip)]
+    history: UndoHistory,
 }
 
 impl Arena {
+    /// Creates a new game with a random number generator seeded from system entropy.
+    #[allow(dead_code)]
     pub fn new(kingdom_set: KingdomSet, num_players: usize) -> Self {
+        Self::with_rng(kingdom_set, num_players, Pcg64Mcg::from_entropy())
+    }
+
+    /// Creates a new game where all shuffles are determined by `seed`.
+    #[allow(dead_code)]
+    pub fn with_seed(kingdom_set: KingdomSet, num_players: usize, seed: u64) -> Self {
+        Self::with_rng(kingdom_set, num_players, Pcg64Mcg::seed_from_u64(seed))
+    }
+
+    /// Creates a new game that uses `rng` for all shuffles. The arena takes
+    /// ownership of `rng` so separate arenas never share a random stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_players` is not from 2 to 6.
+    pub fn with_rng(kingdom_set: KingdomSet, num_players: usize, rng: Pcg64Mcg) -> Self {
+        Self::with_kingdom(&kingdom_set.cards(), num_players, rng)
+            .expect("invalid number of players")
+    }
+
+    /// Creates a new game with the setup given by `config`. Shuffles are the
+    /// same as those of `Arena::with_seed` with the same seed.
+    ///
+    /// # Errors
+    ///
+    /// If `config` is not valid, then the error from `GameConfig::validate` is
+    /// returned.
+    pub fn with_config(config: &GameConfig) -> std::result::Result<Self, ConfigError> {
+        config.validate()?;
+
+        // A valid config always has a valid number of players and supply.
+        Ok(Self::with_supply(
+            &config.kingdom,
+            &config.piles,
+            config.players.len(),
+            Pcg64Mcg::seed_from_u64(config.seed),
+        )
+        .unwrap())
+    }
+
+    /// Creates a new game with any kingdom, such as one from a `Randomizer`,
+    /// that uses `rng` for all shuffles.
+    ///
+    /// # Errors
+    ///
+    /// If `num_players` is not from 2 to 6, then `Error::InvalidPlayerCount` is
+    /// returned. If `kingdom` contains a card that is not a kingdom card or
+    /// contains a card twice, then `Error::InvalidCard` is returned.
+    pub fn with_kingdom(kingdom: &Kingdom, num_players: usize, rng: Pcg64Mcg) -> Result<Self> {
+        Self::with_supply(kingdom, &PileConfig::default(), num_players, rng)
+    }
+
+    /// Creates a new game with any kingdom and the extra and ending piles in
+    /// `piles`, that uses `rng` for all shuffles.
+    ///
+    /// # Errors
+    ///
+    /// If `num_players` is not from 2 to 6, then `Error::InvalidPlayerCount` is
+    /// returned. If `kingdom` contains a card that is not a kingdom card or
+    /// contains a card twice, or if `piles` is not valid for `kingdom`, then
+    /// `Error::InvalidCard` is returned.
+    pub fn with_supply(
+        kingdom: &Kingdom,
+        piles: &PileConfig,
+        num_players: usize,
+        mut rng: Pcg64Mcg,
+    ) -> Result<Self> {
         let mut arena = Self {
-            supply: Supply::new(kingdom_set.cards(), num_players),
+            supply: Supply::new(kingdom, num_players, piles)?,
             trash: CardVec::new(),
-            players: (0..num_players).map(|_| Player::new()).collect(),
+            players: (0..num_players)
+                .map(|id| Player::new(id, &mut rng))
+                .collect(),
             turn: Turn::new(),
             current_player_id: 0,
-            actions: Some(CardActionQueue::new()),
+            actions: CardActionQueue::new(),
+            triggers: Vec::new(),
+            rng,
+            log: EventLog::new(),
+            history: UndoHistory::default(),
         };
 
         arena.start_game();
 
-        arena
+        Ok(arena)
     }
 
     pub fn kingdom(&self) -> impl std::iter::Iterator<Item = &'_ CardKind> {
-        self.supply.kingdom_cards.iter().map(|(card, _)| card)
+        self.supply.kingdom().iter()
     }
 
     pub fn is_game_over(&self) -> bool {
         self.supply.is_game_over()
     }
 
+    /// Returns the current victory points and number of turns taken by each
+    /// player, ordered by player id.
+    pub fn scores(&self) -> Vec<Score> {
+        self.players
+            .iter()
+            .enumerate()
+            .map(|(player_id, player)| Score {
+                player_id,
+                victory_points: player.victory_points(),
+                turns: player.turns,
+            })
+            .collect()
+    }
+
+    /// Returns the final standings or `None` if the game is not over.
+    pub fn result(&self) -> Option<GameResult> {
+        if self.is_game_over() {
+            Some(GameResult::new(&self.scores()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the contents of `location`. The draw pile is hidden so only the
+    /// number of cards is returned.
+    ///
+    /// # Errors
+    ///
+    /// If `location` refers to a player that does not exist, then an error is
+    /// returned.
+    pub fn view(&self, location: types::Location) -> Result<LocationView<'_>> {
+        use types::Location::*;
+
+        match location {
+            Draw { player_id } => Ok(LocationView::Count(self.player(player_id)?.draw_pile.len())),
+            Discard { player_id } => Ok(LocationView::Cards(&self.player(player_id)?.discard_pile)),
+            Hand { player_id } => Ok(LocationView::Cards(&self.player(player_id)?.hand)),
+            Play { player_id } => Ok(LocationView::Cards(&self.player(player_id)?.play_zone)),
+            Stage { player_id } => Ok(LocationView::Cards(&self.player(player_id)?.stage)),
+            Supply => Ok(LocationView::Supply(
+                self.supply
+                    .iter()
+                    .map(|(card, &count)| (card, count))
+                    .collect(),
+            )),
+            Trash => Ok(LocationView::Cards(&self.trash)),
+        }
+    }
+
+    /// Returns a snapshot of the game as seen by `player_id`. Other players'
+    /// hands and the order of every draw pile are hidden.
+    ///
+    /// # Errors
+    ///
+    /// If `player_id` does not exist, then an error is returned.
+    #[allow(dead_code)]
+    pub fn observe(&self, player_id: usize) -> Result<Observation> {
+        let observer = self.player(player_id)?;
+
+        Ok(Observation {
+            player_id,
+            current_player_id: self.current_player_id,
+            turn: self.turn,
+            hand: observer.hand.to_vec(),
+            players: self
+                .players
+                .iter()
+                .enumerate()
+                .map(|(id, player)| PlayerObservation {
+                    hand_size: player.hand.len(),
+                    draw_pile_size: player.draw_pile.len(),
+                    discard_pile_size: player.discard_pile.len(),
+                    discard_top: player.discard_pile.last().copied(),
+                    play_zone: player.play_zone.to_vec(),
+                    stage: if id == player_id {
+                        Some(player.stage.to_vec())
+                    } else {
+                        None
+                    },
+                    stage_size: player.stage.len(),
+                })
+                .collect(),
+            supply: self
+                .supply
+                .iter()
+                .map(|(card, &count)| (card, count))
+                .collect(),
+            trash: self.trash.to_vec(),
+            pending_decision: self.pending_decision().copied(),
+        })
+    }
+
+    /// Returns the decision that must be made before the game can continue.
+    pub fn pending_decision(&self) -> Option<&'static Decision> {
+        self.actions.resolve_condition()
+    }
+
+    /// Returns every event that has happened in the game, in order.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.log.events
+    }
+
+    /// Sets a function to be called with each event as it happens, replacing
+    /// any previous subscriber.
+    #[allow(dead_code)]
+    pub fn subscribe(&mut self, subscriber: impl FnMut(&GameEvent) + Send + 'static) {
+        self.log.subscriber = Some(Box::new(subscriber));
+    }
+
     #[allow(dead_code)]
     pub fn in_deck(&self, player_id: usize, card: CardKind) -> Result<bool> {
         self.player(player_id).map(|player| player.in_deck(card))
@@ -68,35 +278,39 @@ impl Arena {
     }
 
     pub fn end_turn_phase(&mut self) -> Result<()> {
-        match self.turn {
-            Turn::Action(_) => self.end_action_phase(),
-            Turn::Buy(_) => self.end_buy_phase(),
-        }
+        self.undoable(self.current_player_id, |arena| match arena.turn {
+            Turn::Action(_) => arena.end_action_phase(),
+            Turn::Buy(_) => arena.end_buy_phase(),
+        })
     }
 
     fn end_action_phase(&mut self) -> Result<()> {
         self.check_actions_resolved()?;
 
         self.turn = Turn::Buy(self.turn.as_action_phase_mut()?.to_buy_phase());
+        self.emit_phase_change();
 
         Ok(())
     }
 
     fn end_buy_phase(&mut self) -> Result<()> {
         self.check_actions_resolved()?;
-        self.current_player_mut().cleanup();
+        current_player!(self).cleanup(&mut self.rng, &mut self.log);
+        self.triggers.clear();
 
         self.turn = Turn::new();
         self.current_player_id = self.next_player_id();
+        self.current_player_mut().turns += 1;
+        self.emit_phase_change();
 
         Ok(())
     }
 
     pub fn play_card(&mut self, card: CardKind) -> Result<()> {
-        match self.turn {
-            Turn::Action(_) => self.play_action(card),
-            Turn::Buy(_) => self.play_treasure(card),
-        }
+        self.undoable(self.current_player_id, |arena| match arena.turn {
+            Turn::Action(_) => arena.play_action(card),
+            Turn::Buy(_) => arena.play_treasure(card),
+        })
     }
 
     fn play_action(&mut self, card: CardKind) -> Result<()> {
@@ -109,8 +323,12 @@ impl Arena {
             let _ = player.hand.move_card(&mut player.play_zone, card)?;
 
             self.turn.as_action_phase_mut().unwrap().remaining_actions -= 1;
+            self.log.emit(GameEvent::Play {
+                player_id: self.current_player_id,
+                card,
+            });
 
-            self.actions.as_mut().unwrap().add_card(card);
+            self.actions.add_card(card);
             self.try_resolve(self.current_player_id, None)
         } else {
             Err(Error::InvalidCard)
@@ -128,6 +346,11 @@ impl Arena {
             let _ = player.hand.move_card(&mut player.play_zone, card)?;
 
             self.turn.as_buy_phase_mut().unwrap().remaining_copper += additional_copper;
+            self.log.emit(GameEvent::Play {
+                player_id: self.current_player_id,
+                card,
+            });
+            self.fire_triggers(card);
 
             Ok(())
         } else {
@@ -136,6 +359,10 @@ impl Arena {
     }
 
     pub fn buy_card(&mut self, card: CardKind) -> Result<()> {
+        self.undoable(self.current_player_id, |arena| arena.buy(card))
+    }
+
+    fn buy(&mut self, card: CardKind) -> Result<()> {
         self.check_actions_resolved()?;
 
         let &mut turn::BuyPhase {
@@ -156,6 +383,10 @@ impl Arena {
             buy_phase.remaining_buys -= 1;
             buy_phase.remaining_copper -= card.cost();
 
+            let player_id = self.current_player_id;
+            self.log.emit(GameEvent::Buy { player_id, card });
+            self.log.emit(GameEvent::Gain { player_id, card });
+
             Ok(())
         }
     }
@@ -165,33 +396,38 @@ impl Arena {
         if player_id >= self.players.len() {
             Err(Error::InvalidPlayerId)
         } else {
-            self.try_resolve(player_id, Some(cards))
+            self.undoable(player_id, |arena| arena.try_resolve(player_id, Some(cards)))
         }
     }
 
     fn try_resolve(&mut self, player_id: usize, selected_cards: Option<&[CardKind]>) -> Result<()> {
-        let mut temp: Option<CardActionQueue> = None;
-
-        // The Arena contains the ActionEffect to track the state of resolving an action card.
-        // However, the ActionEffect::resolve method requires a mutable reference to the
+        // The Arena contains the CardActionQueue to track the state of resolving an action card.
+        // However, the CardActionQueue::resolve method requires a mutable reference to the
         // Arena as it will need to modify the game state. To prevent more than one mutable borrow,
-        // we swap Some(CardActionQueue) with None.
-        std::mem::swap(&mut temp, &mut self.actions);
+        // the queue is moved out of the Arena while it resolves.
+        let mut actions = std::mem::replace(&mut self.actions, CardActionQueue::new());
 
-        let result = temp
-            .as_mut()
-            .unwrap()
-            .resolve(self, player_id, selected_cards);
+        let result = actions.resolve(self, player_id, selected_cards);
 
-        std::mem::swap(&mut temp, &mut self.actions);
+        self.actions = actions;
 
         result
     }
 
     fn start_game(&mut self) {
         for p in &mut self.players {
-            p.cleanup();
+            p.cleanup(&mut self.rng, &mut self.log);
         }
+
+        self.current_player_mut().turns += 1;
+        self.emit_phase_change();
+    }
+
+    fn emit_phase_change(&mut self) {
+        self.log.emit(GameEvent::PhaseChange {
+            player_id: self.current_player_id,
+            turn: self.turn,
+        });
     }
 
     fn player(&self, player_id: usize) -> Result<&Player> {
@@ -223,19 +459,17 @@ impl Arena {
     }
 
     fn check_actions_resolved(&mut self) -> Result<()> {
-        if self.actions.as_ref().unwrap().is_resolved() {
+        if self.actions.is_resolved() {
             Ok(())
         } else {
             Err(Error::UnresolvedActionEffect(
-                self.actions
-                    .as_ref()
-                    .unwrap()
-                    .resolve_condition()
-                    .unwrap_or(""),
+                self.pending_decision()
+                    .map_or("", |decision| decision.description),
             ))
         }
     }
 
+    #[allow(dead_code)]
     pub fn current_player_id(&self) -> usize {
         self.current_player_id
     }
@@ -246,17 +480,6 @@ mod tests {
     use super::*;
     use crate::dominion::{Arena, KingdomSet};
 
-    impl Supply {
-        fn count(&self, card: CardKind) -> usize {
-            self.find(card)
-                .map(|i| {
-                    let (_, count) = self.get_entry(i).unwrap();
-                    *count
-                })
-                .unwrap()
-        }
-    }
-
     #[test]
     fn player_valid_index() {
         let arena = Arena::new(KingdomSet::FirstGame, 2);
@@ -279,6 +502,264 @@ mod tests {
         assert_eq!(r.unwrap_err(), Error::InvalidPlayerId);
     }
 
+    #[test]
+    fn with_config_validates_config() {
+        let mut config = GameConfig::new(KingdomSet::FirstGame.cards(), 2, 1).unwrap();
+        assert!(Arena::with_config(&config).is_ok());
+
+        config.players.pop();
+        assert_eq!(
+            Arena::with_config(&config).map(|_| ()),
+            Err(ConfigError::InvalidPlayerCount(1))
+        );
+    }
+
+    #[test]
+    fn with_kingdom_rejects_invalid_cards() {
+        let rng = || Pcg64Mcg::seed_from_u64(1);
+
+        let mut kingdom = KingdomSet::FirstGame.cards();
+        kingdom[0] = CardKind::Copper;
+        assert_eq!(
+            Arena::with_kingdom(&kingdom, 2, rng()).map(|_| ()),
+            Err(Error::InvalidCard)
+        );
+
+        let mut kingdom = KingdomSet::FirstGame.cards();
+        kingdom[0] = kingdom[1];
+        assert_eq!(
+            Arena::with_kingdom(&kingdom, 2, rng()).map(|_| ()),
+            Err(Error::InvalidCard)
+        );
+    }
+
+    #[test]
+    fn same_seed_same_game() {
+        let mut a = Arena::with_seed(KingdomSet::FirstGame, 2, 7);
+        let mut b = Arena::with_seed(KingdomSet::FirstGame, 2, 7);
+
+        // Advancing one arena must not perturb the shuffles of the other.
+        let mut hands = Vec::new();
+        for _ in 0..4 {
+            hands.push((a.players[0].hand.clone(), a.players[1].hand.clone()));
+            a.end_turn_phase().unwrap();
+            a.end_turn_phase().unwrap();
+        }
+
+        for (hand0, hand1) in hands {
+            assert_eq!(b.players[0].hand, hand0);
+            assert_eq!(b.players[1].hand, hand1);
+            b.end_turn_phase().unwrap();
+            b.end_turn_phase().unwrap();
+        }
+    }
+
+    #[test]
+    fn scores_count_turns_taken() {
+        let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        assert_eq!(
+            arena.scores(),
+            vec![
+                Score {
+                    player_id: 0,
+                    victory_points: 3,
+                    turns: 1
+                },
+                Score {
+                    player_id: 1,
+                    victory_points: 3,
+                    turns: 0
+                },
+            ]
+        );
+
+        arena.end_turn_phase().unwrap();
+        arena.end_turn_phase().unwrap();
+
+        assert!(arena.scores().iter().all(|score| score.turns == 1));
+    }
+
+    #[test]
+    fn result_only_when_game_over() {
+        let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        assert_eq!(arena.result(), None);
+
+        arena.players[1].discard_pile.push(CardKind::Province);
+        arena.supply.iter_mut().for_each(|(card, count)| {
+            if card == CardKind::Province {
+                *count = 0;
+            }
+        });
+
+        let result = arena.result().unwrap();
+
+        assert_eq!(result.winners().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(result.standings[0].score.victory_points, 9);
+    }
+
+    #[test]
+    fn view_player_locations() {
+        let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        arena.players[1].play_zone.push(CardKind::Village);
+        arena.players[1].stage.push(CardKind::Smithy);
+        arena.trash.push(CardKind::Curse);
+
+        assert_eq!(
+            arena.view(types::Location::Hand { player_id: 0 }),
+            Ok(LocationView::Cards(&arena.players[0].hand))
+        );
+        assert_eq!(
+            arena.view(types::Location::Draw { player_id: 0 }),
+            Ok(LocationView::Count(5))
+        );
+        assert_eq!(
+            arena.view(types::Location::Discard { player_id: 1 }),
+            Ok(LocationView::Cards(&[]))
+        );
+        assert_eq!(
+            arena.view(types::Location::Play { player_id: 1 }),
+            Ok(LocationView::Cards(&[CardKind::Village]))
+        );
+        assert_eq!(
+            arena.view(types::Location::Stage { player_id: 1 }),
+            Ok(LocationView::Cards(&[CardKind::Smithy]))
+        );
+        assert_eq!(
+            arena.view(types::Location::Trash),
+            Ok(LocationView::Cards(&[CardKind::Curse]))
+        );
+        assert_eq!(
+            arena.view(types::Location::Hand { player_id: 2 }),
+            Err(Error::InvalidPlayerId)
+        );
+    }
+
+    #[test]
+    fn view_supply() {
+        let arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        if let Ok(LocationView::Supply(piles)) = arena.view(types::Location::Supply) {
+            assert_eq!(piles.len(), 17);
+            assert!(piles.contains(&(CardKind::Province, 8)));
+            assert!(piles.contains(&(CardKind::Copper, 46)));
+        } else {
+            panic!("expected a view of the supply");
+        }
+    }
+
+    #[test]
+    fn observe_hides_other_players_cards() {
+        let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        arena.players[0].stage.push(CardKind::Gold);
+        arena.players[1].stage.push(CardKind::Silver);
+        arena.players[1].discard_pile.push(CardKind::Estate);
+        arena.players[1].discard_pile.push(CardKind::Duchy);
+
+        let observation = arena.observe(1).unwrap();
+
+        assert_eq!(observation.player_id, 1);
+        assert_eq!(observation.current_player_id, 0);
+        assert_eq!(observation.hand, arena.players[1].hand.to_vec());
+        assert_eq!(observation.pending_decision, None);
+
+        let player = &observation.players[0];
+        assert_eq!(player.hand_size, 5);
+        assert_eq!(player.draw_pile_size, 5);
+        assert_eq!(player.discard_top, None);
+        assert_eq!(player.stage, None);
+        assert_eq!(player.stage_size, 1);
+
+        let observer = &observation.players[1];
+        assert_eq!(observer.discard_pile_size, 2);
+        assert_eq!(observer.discard_top, Some(CardKind::Duchy));
+        assert_eq!(observer.stage, Some(vec![CardKind::Silver]));
+    }
+
+    #[test]
+    fn observe_pending_decision() {
+        let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        arena.players[0].hand.push(CardKind::Militia);
+        let error = arena.play_card(CardKind::Militia).unwrap_err();
+
+        assert_eq!(
+            Error::UnresolvedActionEffect(
+                arena
+                    .observe(0)
+                    .unwrap()
+                    .pending_decision
+                    .unwrap()
+                    .description
+            ),
+            error
+        );
+        assert!(arena.observe(1).unwrap().pending_decision.is_some());
+        assert_eq!(arena.observe(2), Err(Error::InvalidPlayerId));
+    }
+
+    #[test]
+    fn arena_is_send() {
+        fn assert_send<T: Send>() {}
+
+        assert_send::<Arena>();
+    }
+
+    #[test]
+    fn events_record_turn() {
+        use std::sync::{Arc, Mutex};
+
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+
+        // Each player draws 5 cards at the start of the game.
+        assert_eq!(
+            arena.events().first(),
+            Some(&GameEvent::Draw {
+                player_id: 0,
+                card: arena.players[0].hand[0]
+            })
+        );
+        assert_eq!(
+            arena.events().last(),
+            Some(&GameEvent::PhaseChange {
+                player_id: 0,
+                turn: Turn::new()
+            })
+        );
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = Arc::clone(&received);
+        arena.subscribe(move |event| subscriber.lock().unwrap().push(event.clone()));
+
+        arena.players[0].hand.push(CardKind::Silver);
+        arena.end_turn_phase().unwrap();
+        arena.play_card(CardKind::Silver).unwrap();
+        arena.buy_card(CardKind::Copper).unwrap();
+
+        let events = received.lock().unwrap();
+        assert_eq!(
+            events[1..],
+            [
+                GameEvent::Play {
+                    player_id: 0,
+                    card: CardKind::Silver
+                },
+                GameEvent::Buy {
+                    player_id: 0,
+                    card: CardKind::Copper
+                },
+                GameEvent::Gain {
+                    player_id: 0,
+                    card: CardKind::Copper
+                },
+            ]
+        );
+        assert!(arena.events().ends_with(&events));
+    }
+
     #[test]
     fn end_action_phase() {
         let mut arena = Arena::new(KingdomSet::FirstGame, 2);
@@ -327,7 +808,7 @@ mod tests {
             remaining_copper: 0,
         });
 
-        let copper_count = arena.supply.count(CardKind::Copper);
+        let copper_count = arena.supply.count(CardKind::Copper).unwrap();
 
         let r = arena.buy_card(CardKind::Copper);
 
@@ -336,7 +817,10 @@ mod tests {
             arena.player(0).unwrap().discard_pile,
             cardvec![CardKind::Copper]
         );
-        assert_eq!(arena.supply.count(CardKind::Copper), copper_count - 1);
+        assert_eq!(
+            arena.supply.count(CardKind::Copper).unwrap(),
+            copper_count - 1
+        );
         assert_eq!(
             arena.turn,
             Turn::Buy(turn::BuyPhase {
@@ -355,7 +839,7 @@ mod tests {
             remaining_copper: 5,
         });
 
-        let market_count = arena.supply.count(CardKind::Market);
+        let market_count = arena.supply.count(CardKind::Market).unwrap();
 
         let r = arena.buy_card(CardKind::Market);
 
@@ -364,7 +848,10 @@ mod tests {
             arena.player(0).unwrap().discard_pile,
             cardvec![CardKind::Market]
         );
-        assert_eq!(arena.supply.count(CardKind::Market), market_count - 1);
+        assert_eq!(
+            arena.supply.count(CardKind::Market).unwrap(),
+            market_count - 1
+        );
         assert_eq!(
             arena.turn,
             Turn::Buy(turn::BuyPhase {
@@ -501,6 +988,44 @@ mod tests {
         assert_eq!(arena.player(0).unwrap().hand.len(), 1);
     }
 
+    #[test]
+    fn play_treasure_silver_after_merchants() {
+        let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+
+        arena.turn = Turn::Action(turn::ActionPhase {
+            remaining_actions: 2,
+            remaining_buys: 1,
+            remaining_copper: 0,
+        });
+
+        arena.players[0].hand.clear();
+        arena.players[0].hand.push(CardKind::Merchant);
+        arena.players[0].hand.push(CardKind::Merchant);
+        assert!(arena.play_action(CardKind::Merchant).is_ok());
+        assert!(arena.play_action(CardKind::Merchant).is_ok());
+        assert!(arena.end_action_phase().is_ok());
+
+        arena.players[0].hand.clear();
+        arena.players[0].hand.push(CardKind::Copper);
+        arena.players[0].hand.push(CardKind::Silver);
+        arena.players[0].hand.push(CardKind::Silver);
+
+        // Copper does not fire the Merchant triggers.
+        assert!(arena.play_treasure(CardKind::Copper).is_ok());
+        assert_eq!(arena.turn.as_buy_phase_mut().unwrap().remaining_copper, 1);
+
+        // Each Merchant adds $1 on the first Silver only.
+        assert!(arena.play_treasure(CardKind::Silver).is_ok());
+        assert_eq!(arena.turn.as_buy_phase_mut().unwrap().remaining_copper, 5);
+
+        assert!(arena.play_treasure(CardKind::Silver).is_ok());
+        assert_eq!(arena.turn.as_buy_phase_mut().unwrap().remaining_copper, 7);
+
+        // Triggers do not carry over into the next turn.
+        assert!(arena.end_buy_phase().is_ok());
+        assert!(arena.triggers.is_empty());
+    }
+
     #[test]
     fn play_treasuse_gold_during_buy_phase() {
         let mut arena = Arena::new(KingdomSet::FirstGame, 2);
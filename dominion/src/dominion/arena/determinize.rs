@@ -0,0 +1,150 @@
+use super::log::EventLog;
+use super::undo::UndoHistory;
+use crate::dominion::location::CardVec;
+use crate::dominion::types::Result;
+use crate::dominion::Arena;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+
+impl Arena {
+    /// Returns a copy of the game for searching ahead. The copy has an empty
+    /// event log and never records moves for undo, so neither is copied.
+    pub fn clone_for_search(&self) -> Self {
+        Self {
+            supply: self.supply.clone(),
+            trash: self.trash.clone(),
+            players: self.players.clone(),
+            turn: self.turn,
+            current_player_id: self.current_player_id,
+            actions: self.actions.clone(),
+            triggers: self.triggers.clone(),
+            rng: self.rng.clone(),
+            log: EventLog::new(),
+            history: UndoHistory::disabled(),
+        }
+    }
+
+    /// Returns a copy of the game in which the cards hidden from `player_id`
+    /// are replaced with a random guess that is consistent with what the
+    /// player can observe. Other players' hands and set aside cards are dealt
+    /// again from those cards and their draw pile, every draw pile is shuffled
+    /// and later shuffles use a new random number generator. The copy is made
+    /// with `clone_for_search` as the event log records hidden cards.
+    ///
+    /// # Errors
+    ///
+    /// If `player_id` does not exist, then an error is returned.
+    pub fn determinize(&self, player_id: usize, rng: &mut impl Rng) -> Result<Self> {
+        self.player(player_id)?;
+
+        let mut arena = self.clone_for_search();
+
+        for player in &mut arena.players {
+            if player.id == player_id {
+                player.draw_pile.shuffle(rng);
+            } else {
+                let hand_size = player.hand.len();
+                let stage_size = player.stage.len();
+
+                player.draw_pile.append(&mut player.hand);
+                player.draw_pile.append(&mut player.stage);
+                player.draw_pile.shuffle(rng);
+
+                let n = player.draw_pile.len() - hand_size;
+                player.hand = CardVec(player.draw_pile.split_off(n));
+                let n = player.draw_pile.len() - stage_size;
+                player.stage = CardVec(player.draw_pile.split_off(n));
+            }
+        }
+
+        arena.rng = Pcg64Mcg::seed_from_u64(rng.gen());
+
+        Ok(arena)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind, KingdomSet};
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    fn sorted(cards: &[CardKind]) -> Vec<String> {
+        let mut cards: Vec<_> = cards.iter().map(|card| format!("{:?}", card)).collect();
+        cards.sort();
+        cards
+    }
+
+    #[test]
+    fn determinize_hides_other_players_cards() {
+        let arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut rng = Pcg64Mcg::seed_from_u64(2);
+
+        let sample = arena.determinize(0, &mut rng).unwrap();
+
+        assert_eq!(sample.players[0].hand, arena.players[0].hand);
+        assert_eq!(
+            sorted(&sample.players[0].draw_pile),
+            sorted(&arena.players[0].draw_pile)
+        );
+
+        let (before, after) = (&arena.players[1], &sample.players[1]);
+        assert_eq!(after.hand.len(), before.hand.len());
+        assert_eq!(
+            sorted(&[&after.hand[..], &after.draw_pile[..]].concat()),
+            sorted(&[&before.hand[..], &before.draw_pile[..]].concat())
+        );
+
+        assert!(sample.events().is_empty());
+        assert_eq!(sample.observe(0).unwrap(), arena.observe(0).unwrap());
+    }
+
+    #[test]
+    fn determinize_hides_other_players_stage() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        arena.players[1].stage.push(CardKind::Smithy);
+        let mut rng = Pcg64Mcg::seed_from_u64(2);
+
+        let samples: Vec<_> = (0..20)
+            .map(|_| arena.determinize(0, &mut rng).unwrap())
+            .collect();
+
+        for sample in &samples {
+            let (before, after) = (&arena.players[1], &sample.players[1]);
+            assert_eq!(after.stage.len(), 1);
+            assert_eq!(
+                sorted(&[&after.hand[..], &after.draw_pile[..], &after.stage[..]].concat()),
+                sorted(&[&before.hand[..], &before.draw_pile[..], &before.stage[..]].concat())
+            );
+        }
+        assert!(samples
+            .iter()
+            .any(|sample| sample.players[1].stage[0] != CardKind::Smithy));
+    }
+
+    #[test]
+    fn search_copy_does_not_record_undo() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        arena.end_turn_phase().unwrap();
+
+        let mut copy = arena.clone_for_search();
+        assert!(copy.events().is_empty());
+
+        copy.end_turn_phase().unwrap();
+        assert_eq!(copy.undo(), Err(Error::NothingToUndo));
+        assert_eq!(arena.undo(), Ok(()));
+    }
+
+    #[test]
+    fn determinize_invalid_player() {
+        let arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        let mut rng = Pcg64Mcg::seed_from_u64(2);
+
+        assert_eq!(
+            arena.determinize(2, &mut rng).map(|_| ()),
+            Err(Error::InvalidPlayerId)
+        );
+    }
+}
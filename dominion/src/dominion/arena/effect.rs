@@ -1,31 +1,45 @@
+use super::save::{self, Named};
+use super::trigger::Trigger;
+use crate::dominion::decision::Decision;
 use crate::dominion::types::{Error, Result};
 use crate::dominion::{Arena, CardKind};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 // Define the effect implementation prelude
 mod prelude;
 
 // Each card effect is defined in it's own file.
+mod artisan;
+mod bandit;
 mod bureaucrat;
 mod cellar;
 mod chapel;
+mod council_room;
 mod harbinger;
+mod library;
+mod merchant;
 mod militia;
+mod mine;
 mod moneylender;
 mod poacher;
+mod reaction;
+mod remodel;
+mod sentry;
 mod throne_room;
 mod vassal;
+mod witch;
 mod workshop;
 
 pub(self) enum Effect {
-    Conditional(ConditionalFunction, &'static str),
+    Conditional(ConditionalFunction, &'static Decision),
     Unconditional(UnconditionalFunction),
 }
 
 impl Effect {
     fn description(&self) -> &'static str {
         match self {
-            Effect::Conditional(_, desc) => desc,
+            Effect::Conditional(_, decision) => decision.description,
             Effect::Unconditional(_) => "",
         }
     }
@@ -34,7 +48,9 @@ impl Effect {
 impl std::fmt::Debug for Effect {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Effect::Conditional(_, desc) => write!(f, "Effect::Conditional({:?})", desc),
+            Effect::Conditional(_, decision) => {
+                write!(f, "Effect::Conditional({:?})", decision.description)
+            }
             Effect::Unconditional(_) => write!(f, "Effect::Unconditional"),
         }
     }
@@ -54,8 +70,8 @@ impl PartialEq for Effect {
         //      >;
         // To compare equality, we dereference and then cast the result to a regular pointer.
         match (self, other) {
-            (Conditional(f1, s1), Conditional(f2, s2)) => {
-                (*f1 as *const () == *f2 as *const ()) && (s1 == s2)
+            (Conditional(f1, d1), Conditional(f2, d2)) => {
+                (*f1 as *const () == *f2 as *const ()) && (d1 == d2)
             }
             (Unconditional(f1), Unconditional(f2)) => *f1 as *const () == *f2 as *const (),
             _ => false,
@@ -74,9 +90,10 @@ type ConditionalFunction =
     fn(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome>;
 type UnconditionalFunction = fn(arena: &mut Arena, player_id: usize, card: CardKind) -> Outcome;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 struct CardAction {
     card: CardKind,
+    #[serde(with = "save::static_refs")]
     effects: VecDeque<&'static Effect>,
 }
 impl CardAction {
@@ -84,10 +101,14 @@ impl CardAction {
         let mut effects = VecDeque::new();
 
         effects.push_back(ADD_RESOURCES_FUNC);
+        if card.is_attack() {
+            effects.push_back(reaction::EFFECT);
+        }
         match card {
             CardKind::Cellar => effects.push_back(cellar::EFFECT),
             CardKind::Chapel => effects.push_back(chapel::EFFECT),
             CardKind::Harbinger => effects.push_back(harbinger::EFFECT),
+            CardKind::Merchant => effects.push_back(merchant::EFFECT),
             CardKind::Vassal => effects.push_back(vassal::EFFECT),
             CardKind::Workshop => effects.push_back(workshop::EFFECT),
             CardKind::Bureaucrat => {
@@ -97,17 +118,19 @@ impl CardAction {
             CardKind::Militia => effects.push_back(militia::EFFECT),
             CardKind::Moneylender => effects.push_back(moneylender::EFFECT),
             CardKind::Poacher => effects.push_back(poacher::EFFECT),
-            //CardKind::Remodel => unimplemeted!(),
+            CardKind::Remodel => effects.push_back(remodel::EFFECT),
             CardKind::ThroneRoom => effects.push_back(throne_room::EFFECT),
-            //CardKind::Bandit => unimplemeted!(),
-            //CardKind::CouncilRoom => unimplemeted!(),
-            //CardKind::Festival => unimplemeted!(),
-            //CardKind::Laboratory => unimplemeted!(),
-            //CardKind::Library => unimplemeted!(),
-            //CardKind::Mine => unimplemeted!(),
-            //CardKind::Sentry => unimplemeted!(),
-            //CardKind::Witch => unimplemeted!(),
-            //CardKind::Artisan => unimplemeted!(),
+            CardKind::Bandit => {
+                effects.push_back(bandit::EFFECT_A);
+                effects.push_back(bandit::EFFECT_B);
+            }
+            CardKind::CouncilRoom => effects.push_back(council_room::EFFECT),
+            CardKind::Library => effects.push_back(library::EFFECT),
+            CardKind::Mine => effects.push_back(mine::EFFECT),
+            CardKind::Sentry => effects.push_back(sentry::EFFECT),
+            CardKind::Witch => effects.push_back(witch::EFFECT),
+            CardKind::Artisan => effects.push_back(artisan::EFFECT),
+            // Remaining cards only add resources.
             _ => (),
         }
 
@@ -121,12 +144,14 @@ impl CardAction {
         selected_cards: Option<&[CardKind]>,
     ) -> (Option<Error>, CardActionQueue) {
         let mut actions = CardActionQueue::new();
+        let mut selected_cards = selected_cards;
 
         while !self.effects.is_empty() {
             let result = match self.effects.front().unwrap() {
-                Effect::Conditional(f, desc) => match selected_cards {
+                // Selected cards only apply to a single conditional effect.
+                Effect::Conditional(f, decision) => match selected_cards.take() {
                     Some(cards) => f(arena, player_id, cards),
-                    None => Err(Error::UnresolvedActionEffect(desc)),
+                    None => Err(Error::UnresolvedActionEffect(decision.description)),
                 },
                 Effect::Unconditional(f) => Ok(f(arena, player_id, self.card)),
             };
@@ -135,7 +160,8 @@ impl CardAction {
                 Ok(mut outcome) => {
                     match &mut outcome {
                         Outcome::Actions(a) => actions.append(a),
-                        Outcome::Effect(e) => self.effects.push_back(e),
+                        // A spawned effect resolves directly after the effect that spawned it.
+                        Outcome::Effect(e) => self.effects.insert(1, e),
                         Outcome::None => (),
                     }
                     self.effects.pop_front();
@@ -147,10 +173,10 @@ impl CardAction {
         (None, actions)
     }
 
-    fn condition(&self) -> Option<&'static str> {
+    fn condition(&self) -> Option<&'static Decision> {
         self.effects.iter().find_map(|&x| {
-            if let Effect::Conditional(_, desc) = *x {
-                Some(desc)
+            if let Effect::Conditional(_, decision) = *x {
+                Some(decision)
             } else {
                 None
             }
@@ -158,7 +184,7 @@ impl CardAction {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub(super) struct CardActionQueue {
     actions: VecDeque<CardAction>,
 }
@@ -190,7 +216,8 @@ impl CardActionQueue {
         self.actions.is_empty()
     }
 
-    pub(super) fn resolve_condition(&self) -> Option<&'static str> {
+    /// Returns the decision that must be made for the next conditional effect.
+    pub(super) fn resolve_condition(&self) -> Option<&'static Decision> {
         self.actions.iter().find_map(CardAction::condition)
     }
 
@@ -234,9 +261,8 @@ fn add_resources_func(arena: &mut Arena, _: usize, card: CardKind) -> Outcome {
         action_phase.remaining_buys += resources.buys;
         action_phase.remaining_copper += resources.copper;
 
-        let player = arena.current_player_mut();
         for _ in 0..resources.cards {
-            player.draw_card();
+            current_player!(arena).draw_card(&mut arena.rng, &mut arena.log);
         }
     }
 
@@ -246,12 +272,57 @@ fn add_resources_func(arena: &mut Arena, _: usize, card: CardKind) -> Outcome {
 
 const ADD_RESOURCES_FUNC: &Effect = &Effect::Unconditional(add_resources_func);
 
+impl Named for Effect {
+    const REGISTRY: &'static [(&'static str, &'static Self)] = &[
+        ("AddResources", ADD_RESOURCES_FUNC),
+        ("Artisan", artisan::EFFECT),
+        ("Artisan.Secondary", artisan::SECONDARY_EFFECT),
+        ("Bandit.A", bandit::EFFECT_A),
+        ("Bandit.B", bandit::EFFECT_B),
+        ("Bandit.Secondary", bandit::SECONDARY_EFFECT),
+        ("Bureaucrat.A", bureaucrat::EFFECT_A),
+        ("Bureaucrat.B", bureaucrat::EFFECT_B),
+        ("Cellar", cellar::EFFECT),
+        ("Chapel", chapel::EFFECT),
+        ("CouncilRoom", council_room::EFFECT),
+        ("Harbinger", harbinger::EFFECT),
+        ("Library", library::EFFECT),
+        ("Library.Secondary", library::SECONDARY_EFFECT),
+        ("Merchant", merchant::EFFECT),
+        ("Militia", militia::EFFECT),
+        ("Mine", mine::EFFECT),
+        ("Mine.Secondary", mine::SECONDARY_EFFECT),
+        ("Moneylender", moneylender::EFFECT),
+        ("Poacher", poacher::EFFECT),
+        ("Poacher.Secondary", poacher::SECONDARY_EFFECT),
+        ("Reaction", reaction::EFFECT),
+        ("Reaction.Secondary", reaction::SECONDARY_EFFECT),
+        ("Remodel", remodel::EFFECT),
+        ("Remodel.Secondary", remodel::SECONDARY_EFFECT),
+        ("Sentry", sentry::EFFECT),
+        ("Sentry.Trash", sentry::TRASH_EFFECT),
+        ("Sentry.Discard", sentry::DISCARD_EFFECT),
+        ("Sentry.Order", sentry::ORDER_EFFECT),
+        ("ThroneRoom", throne_room::EFFECT),
+        ("Vassal", vassal::EFFECT),
+        ("Vassal.Secondary", vassal::SECONDARY_EFFECT),
+        ("Witch", witch::EFFECT),
+        ("Workshop", workshop::EFFECT),
+    ];
+}
+
+// Triggers are registered by effects so they are named here as well.
+impl Named for Trigger {
+    const REGISTRY: &'static [(&'static str, &'static Self)] = &[("Merchant", merchant::TRIGGER)];
+}
+
 #[cfg(test)]
 mod test_util;
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::dominion::location::CardVec;
     use crate::dominion::turn::{self, Turn};
 
     #[test]
@@ -342,6 +413,141 @@ mod test {
         );
     }
 
+    #[test]
+    fn resolve_remodel_stack() {
+        let (mut arena, mut actions) = test_util::setup_arena_actions();
+        arena.current_player_mut().hand.push(CardKind::Silver);
+        actions.add_card(CardKind::Remodel);
+
+        let r = actions.resolve(&mut arena, 0, None);
+
+        assert_eq!(
+            r,
+            Err(Error::UnresolvedActionEffect(
+                "Trash a card from your hand."
+            ))
+        );
+        assert!(!actions.is_resolved());
+
+        // Selecting the card to trash must not also select the card to gain.
+        let trash_cards = vec![CardKind::Silver];
+        let r = actions.resolve(&mut arena, 0, Some(&trash_cards));
+
+        assert_eq!(
+            r,
+            Err(Error::UnresolvedActionEffect(
+                "Gain a card costing up to $2 more than the trashed card."
+            ))
+        );
+        assert!(!actions.is_resolved());
+        assert_eq!(arena.trash, cardvec![CardKind::Silver]);
+
+        let gain_cards = vec![CardKind::Market];
+        let r = actions.resolve(&mut arena, 0, Some(&gain_cards));
+
+        assert_eq!(r, Ok(()));
+        assert!(actions.is_resolved());
+        assert_eq!(
+            arena.current_player().discard_pile,
+            cardvec![CardKind::Market]
+        );
+    }
+
+    #[test]
+    fn resolve_condition_describes_decision() {
+        use crate::dominion::decision::{CardCount, CardFilter, Chooser, CostBound, Zone};
+
+        let (mut arena, mut actions) = test_util::setup_arena_actions();
+        arena.current_player_mut().hand.push(CardKind::Silver);
+        actions.add_card(CardKind::Mine);
+
+        assert!(actions.resolve(&mut arena, 0, None).is_err());
+
+        let decision = actions.resolve_condition().unwrap();
+        assert_eq!(decision.chooser, Chooser::CurrentPlayer);
+        assert_eq!(decision.zone, Zone::Hand);
+        assert_eq!(decision.count, CardCount::Exactly(1));
+        assert_eq!(decision.filter, CardFilter::Treasure);
+        assert_eq!(decision.max_cost, None);
+        assert!(decision.optional);
+
+        assert!(actions
+            .resolve(&mut arena, 0, Some(&[CardKind::Silver]))
+            .is_err());
+
+        let decision = actions.resolve_condition().unwrap();
+        assert_eq!(decision.zone, Zone::Supply);
+        assert_eq!(decision.filter, CardFilter::Treasure);
+        assert_eq!(decision.max_cost, Some(CostBound::UpToTrashedPlus(3)));
+        assert!(!decision.optional);
+    }
+
+    #[test]
+    fn resolve_witch_stack() {
+        let (mut arena, mut actions) = test_util::setup_arena_actions();
+        actions.add_card(CardKind::Witch);
+
+        let r = actions.resolve(&mut arena, 0, None);
+
+        assert_eq!(r, Ok(()));
+        assert!(actions.is_resolved());
+
+        assert_eq!(arena.current_player().hand.len(), 7);
+        assert_eq!(
+            arena.player(1).unwrap().discard_pile,
+            cardvec![CardKind::Curse]
+        );
+    }
+
+    #[test]
+    fn resolve_militia_stack_with_moat() {
+        let (mut arena, mut actions) = test_util::setup_arena_actions();
+        arena.player_mut(1).unwrap().hand.push(CardKind::Moat);
+        actions.add_card(CardKind::Militia);
+
+        let r = actions.resolve(&mut arena, 0, None);
+
+        // The attacked player is given the chance to reveal a Reaction first.
+        assert_eq!(
+            r,
+            Err(Error::UnresolvedActionEffect(
+                "Each other player may reveal a Reaction card from their hand."
+            ))
+        );
+        assert!(!actions.is_resolved());
+
+        let reveal_cards = vec![CardKind::Moat];
+        let r = actions.resolve(&mut arena, 1, Some(&reveal_cards));
+
+        assert_eq!(
+            r,
+            Err(Error::UnresolvedActionEffect(
+                "Each other player discards down to 3 cards in their hand."
+            ))
+        );
+        assert!(!actions.is_resolved());
+
+        // The immune player can't be made to discard.
+        let discard_cards: Vec<_> = arena
+            .player(1)
+            .unwrap()
+            .hand
+            .iter()
+            .take(3)
+            .cloned()
+            .collect();
+        let r = actions.resolve(&mut arena, 1, Some(&discard_cards));
+
+        assert!(r.is_err());
+        assert!(!actions.is_resolved());
+
+        let r = actions.resolve(&mut arena, 1, Some(&[]));
+
+        assert_eq!(r, Ok(()));
+        assert!(actions.is_resolved());
+        assert_eq!(arena.player(1).unwrap().hand.len(), 6);
+    }
+
     #[test]
     fn resolve_throne_room_stack_no_action() {
         let (mut arena, mut actions) = test_util::setup_arena_actions();
@@ -468,4 +674,12 @@ mod test {
             })
         );
     }
+
+    #[test]
+    fn effects_are_saved_by_unique_name() {
+        for &(name, effect) in Effect::REGISTRY {
+            assert_eq!(effect.name(), Some(name));
+            assert_eq!(Effect::from_name(name), Some(effect));
+        }
+    }
 }
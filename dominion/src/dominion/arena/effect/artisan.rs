@@ -0,0 +1,218 @@
+use super::prelude::*;
+
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    gain,
+    &Decision {
+        description: "Gain a card to your hand costing up to $5.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Supply,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Any,
+        max_cost: Some(CostBound::UpTo(5)),
+        optional: false,
+    },
+);
+
+fn gain(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    if cards.is_empty() {
+        if arena
+            .supply
+            .iter()
+            .any(|(card, &count)| (card.cost() <= 5) && (count > 0))
+        {
+            // Player could have selected a card but didn't.
+            error
+        } else {
+            Ok(Outcome::Effect(SECONDARY_EFFECT))
+        }
+    } else if (cards.len() == 1) && (cards[0].cost() <= 5) {
+        arena
+            .supply
+            .move_card(&mut current_player!(arena).hand, cards[0])
+            .map(|card| {
+                arena.log.emit(GameEvent::Gain { player_id, card });
+                Outcome::Effect(SECONDARY_EFFECT)
+            })
+            .or(error)
+    } else {
+        error
+    }
+}
+
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    put,
+    &Decision {
+        description: "Put a card from your hand onto your deck.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: false,
+    },
+);
+
+fn put(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(
+        &SECONDARY_EFFECT.description(),
+    ));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    let player = arena.current_player_mut();
+
+    if cards.is_empty() {
+        if player.hand.is_empty() {
+            Ok(Outcome::None)
+        } else {
+            // Player must put a card onto their deck but didn't.
+            error
+        }
+    } else if cards.len() == 1 {
+        player
+            .hand
+            .move_card(&mut player.draw_pile, cards[0])
+            .map(|card| {
+                arena.log.emit(GameEvent::PutOnDeck { player_id, card });
+                Outcome::None
+            })
+            .or(error)
+    } else {
+        error
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::CardKind;
+
+    #[test]
+    fn gain_valid_card_to_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Market];
+
+        assert!(cards[0].cost() <= 5);
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Ok(Outcome::Effect(SECONDARY_EFFECT))
+        );
+        assert!(arena.current_player().hand.contains(&cards[0]));
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn gain_card_invalid_cost() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert!(cards[0].cost() > 5);
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert!(!arena.current_player().hand.contains(&cards[0]));
+    }
+
+    #[test]
+    fn gain_no_card_with_valid_candidates() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+    }
+
+    #[test]
+    fn gain_no_card_without_valid_candidates() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        arena.supply.iter_mut().for_each(|(card, count)| {
+            if card.cost() <= 5 {
+                *count = 0;
+            }
+        });
+
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Ok(Outcome::Effect(SECONDARY_EFFECT))
+        );
+    }
+
+    #[test]
+    fn put_card_onto_deck() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Market];
+
+        arena.current_player_mut().hand.push(cards[0]);
+
+        assert_eq!(put(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert!(!arena.current_player().hand.contains(&cards[0]));
+        assert_eq!(arena.current_player().draw_pile.last(), Some(&cards[0]));
+    }
+
+    #[test]
+    fn put_nothing_with_cards_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        assert_eq!(
+            put(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+    }
+
+    #[test]
+    fn put_nothing_with_empty_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        arena.current_player_mut().hand.clear();
+
+        assert_eq!(put(&mut arena, player_id, &cards), Ok(Outcome::None));
+    }
+
+    #[test]
+    fn put_card_not_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert_eq!(
+            put(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+    }
+}
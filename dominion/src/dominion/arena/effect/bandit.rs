@@ -0,0 +1,327 @@
+use super::prelude::*;
+
+// "Gain a Gold."
+pub(super) const EFFECT_A: &Effect = &Effect::Unconditional(gain_gold);
+
+fn gain_gold(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    // This can fail if the supply count for Gold is empty but it doesn't matter.
+    if let Ok(card) = arena
+        .supply
+        .move_card(&mut current_player!(arena).discard_pile, CardKind::Gold)
+    {
+        arena.log.emit(GameEvent::Gain {
+            player_id: arena.current_player_id,
+            card,
+        });
+    }
+
+    Outcome::None
+}
+
+// "Each other player reveals the top two cards of their deck, trashes a
+// revealed Treasure other than Copper, and discards the rest."
+pub(super) const EFFECT_B: &Effect = &Effect::Unconditional(reveal);
+
+fn is_trashable(card: CardKind) -> bool {
+    CardFilter::TreasureOtherThanCopper.allows(card)
+}
+
+fn reveal(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    let current_player_id = arena.current_player_id;
+    let rng = &mut arena.rng;
+    let log = &mut arena.log;
+
+    for (player_id, player) in arena.players.iter_mut().enumerate() {
+        if (player_id == current_player_id) || player.immune {
+            continue;
+        }
+
+        // TODO: Reveal cards to other players
+        // Revealed cards are kept in the stage until they are trashed or discarded.
+        for _ in 0..2 {
+            if let Some(card) = player.stage_card(rng, log) {
+                log.emit(GameEvent::Reveal { player_id, card });
+            }
+        }
+
+        let mut trashable: Vec<_> = player
+            .stage
+            .iter()
+            .copied()
+            .filter(|&card| is_trashable(card))
+            .collect();
+        trashable.dedup();
+
+        match trashable.len() {
+            0 => {
+                log.emit_all(&player.stage, |card| GameEvent::Discard { player_id, card });
+                player.discard_pile.append(&mut player.stage);
+            }
+            1 => {
+                let card = player
+                    .stage
+                    .move_card(&mut arena.trash, trashable[0])
+                    .unwrap();
+                log.emit(GameEvent::Trash { player_id, card });
+                log.emit_all(&player.stage, |card| GameEvent::Discard { player_id, card });
+                player.discard_pile.append(&mut player.stage);
+            }
+            // Player must choose which of the two Treasures is trashed.
+            _ => (),
+        }
+    }
+
+    if arena.players.iter().any(|player| !player.stage.is_empty()) {
+        Outcome::Effect(SECONDARY_EFFECT)
+    } else {
+        Outcome::None
+    }
+}
+
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    trash,
+    &Decision {
+        description: "Trash a revealed Treasure other than Copper, and discard the rest.",
+        chooser: Chooser::OtherPlayers,
+        zone: Zone::Stage,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::TreasureOtherThanCopper,
+        max_cost: None,
+        optional: false,
+    },
+);
+
+fn trash(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(
+        &SECONDARY_EFFECT.description(),
+    ));
+
+    if player_id == arena.current_player_id {
+        return error;
+    }
+
+    let player = match arena.players.get_mut(player_id) {
+        // Only players with revealed cards left in their stage have to choose.
+        Some(player) if !player.stage.is_empty() => player,
+        _ => return error,
+    };
+
+    if (cards.len() == 1)
+        && is_trashable(cards[0])
+        && player.stage.move_card(&mut arena.trash, cards[0]).is_ok()
+    {
+        arena.log.emit(GameEvent::Trash {
+            player_id,
+            card: cards[0],
+        });
+        arena
+            .log
+            .emit_all(&player.stage, |card| GameEvent::Discard { player_id, card });
+        player.discard_pile.append(&mut player.stage);
+    } else {
+        return error;
+    }
+
+    if arena.players.iter().any(|player| !player.stage.is_empty()) {
+        // Wait for the remaining players to choose.
+        Ok(Outcome::Effect(SECONDARY_EFFECT))
+    } else {
+        Ok(Outcome::None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind};
+
+    #[test]
+    fn gain_gold_to_discard_pile() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            gain_gold(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::None
+        );
+        assert_eq!(
+            arena.current_player().discard_pile,
+            cardvec![CardKind::Gold]
+        );
+    }
+
+    fn setup_reveal(top_cards: &[CardKind]) -> (Arena, usize) {
+        let mut arena = test_util::setup_arena();
+        let other_player_id = arena.next_player_id();
+
+        let player = arena.player_mut(other_player_id).unwrap();
+        player.draw_pile.clear();
+        player.discard_pile.clear();
+        for &card in top_cards.iter().rev() {
+            player.draw_pile.push(card);
+        }
+
+        (arena, other_player_id)
+    }
+
+    #[test]
+    fn reveal_no_treasures() {
+        let (mut arena, player_id) = setup_reveal(&[CardKind::Estate, CardKind::Copper]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            reveal(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::None
+        );
+
+        let player = arena.player(player_id).unwrap();
+        assert_eq!(player.stage, cardvec![]);
+        assert_eq!(player.discard_pile.len(), 2);
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn reveal_one_treasure() {
+        let (mut arena, player_id) = setup_reveal(&[CardKind::Gold, CardKind::Copper]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            reveal(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::None
+        );
+
+        let player = arena.player(player_id).unwrap();
+        assert_eq!(player.stage, cardvec![]);
+        assert_eq!(player.discard_pile, cardvec![CardKind::Copper]);
+        assert_eq!(arena.trash, cardvec![CardKind::Gold]);
+    }
+
+    #[test]
+    fn reveal_two_identical_treasures() {
+        let (mut arena, player_id) = setup_reveal(&[CardKind::Silver, CardKind::Silver]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            reveal(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::None
+        );
+
+        let player = arena.player(player_id).unwrap();
+        assert_eq!(player.discard_pile, cardvec![CardKind::Silver]);
+        assert_eq!(arena.trash, cardvec![CardKind::Silver]);
+    }
+
+    #[test]
+    fn reveal_two_different_treasures() {
+        let (mut arena, player_id) = setup_reveal(&[CardKind::Silver, CardKind::Gold]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            reveal(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::Effect(SECONDARY_EFFECT)
+        );
+        assert_eq!(arena.player(player_id).unwrap().stage.len(), 2);
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn reveal_while_immune() {
+        let (mut arena, player_id) = setup_reveal(&[CardKind::Gold, CardKind::Copper]);
+        let ignored_player_id = arena.current_player_id;
+
+        arena.player_mut(player_id).unwrap().immune = true;
+
+        assert_eq!(
+            reveal(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::None
+        );
+        assert_eq!(arena.player(player_id).unwrap().draw_pile.len(), 2);
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn reveal_empty_deck() {
+        let (mut arena, player_id) = setup_reveal(&[]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            reveal(&mut arena, ignored_player_id, CardKind::Bandit),
+            Outcome::None
+        );
+        assert_eq!(arena.player(player_id).unwrap().stage, cardvec![]);
+    }
+
+    fn setup_trash() -> (Arena, usize) {
+        let mut arena = test_util::setup_arena();
+        let other_player_id = arena.next_player_id();
+
+        let player = arena.player_mut(other_player_id).unwrap();
+        player.discard_pile.clear();
+        player.stage.push(CardKind::Silver);
+        player.stage.push(CardKind::Gold);
+
+        (arena, other_player_id)
+    }
+
+    #[test]
+    fn trash_revealed_treasure() {
+        let (mut arena, player_id) = setup_trash();
+
+        let cards = [CardKind::Gold];
+
+        assert_eq!(trash(&mut arena, player_id, &cards), Ok(Outcome::None));
+
+        let player = arena.player(player_id).unwrap();
+        assert_eq!(player.stage, cardvec![]);
+        assert_eq!(player.discard_pile, cardvec![CardKind::Silver]);
+        assert_eq!(arena.trash, cardvec![CardKind::Gold]);
+    }
+
+    #[test]
+    fn trash_nothing() {
+        let (mut arena, player_id) = setup_trash();
+
+        let cards = [];
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.player(player_id).unwrap().stage.len(), 2);
+    }
+
+    #[test]
+    fn trash_card_not_revealed() {
+        let (mut arena, player_id) = setup_trash();
+
+        let cards = [CardKind::Copper];
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.player(player_id).unwrap().stage.len(), 2);
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_by_current_player() {
+        let (mut arena, _) = setup_trash();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+    }
+}
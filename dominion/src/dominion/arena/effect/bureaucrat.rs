@@ -1,20 +1,34 @@
 use super::prelude::*;
+use super::reaction;
 
 // "Gain a silver card; put it on top of your deck."
 pub(super) const EFFECT_A: &Effect = &Effect::Unconditional(gain_silver);
 
 fn gain_silver(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
     // This can fail if the supply count for Silver is empty but it doesn't matter.
-    let _ = arena
+    if let Ok(card) = arena
         .supply
-        .move_card(&mut current_player!(arena).draw_pile, CardKind::Silver);
+        .move_card(&mut current_player!(arena).draw_pile, CardKind::Silver)
+    {
+        let player_id = arena.current_player_id;
+        arena.log.emit(GameEvent::Gain { player_id, card });
+        arena.log.emit(GameEvent::PutOnDeck { player_id, card });
+    }
 
     Outcome::None
 }
 
-pub(super) const EFFECT_B: &Effect = &Effect::Conditional (
+pub(super) const EFFECT_B: &Effect = &Effect::Conditional(
     reveal_victory_card,
-    "Each other player reveals a Victory card from his hand and puts it on his deck (or reveals a hand with no Victory cards)."
+    &Decision {
+        description: "Each other player reveals a Victory card from his hand and puts it on his deck (or reveals a hand with no Victory cards).",
+        chooser: Chooser::OtherPlayers,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Victory,
+        max_cost: None,
+        optional: false,
+    },
 );
 
 fn reveal_victory_card(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
@@ -24,32 +38,38 @@ fn reveal_victory_card(arena: &mut Arena, player_id: usize, cards: &[CardKind])
         return error;
     }
 
-    if cards.is_empty() {
+    let player = match arena.player(player_id) {
+        // Each other player reveals only once.
+        Ok(player) if !player.responded => player,
+        _ => return error,
+    };
+
+    if player.immune {
+        // Player revealed a Reaction and is unaffected.
+        if !cards.is_empty() {
+            return error;
+        }
+    } else if cards.is_empty() {
         // No card selected
-        if arena
-            .player(player_id)
-            .unwrap()
-            .hand
-            .iter()
-            .any(|&card| card.is_victory())
-        {
+        if player.hand.iter().any(|&card| card.is_victory()) {
             // Player can reveal a victory card but didn't.
-            error
-        } else {
-            // TODO: Reveal hand to other players
-            Ok(Outcome::None)
+            return error;
         }
+        // TODO: Reveal hand to other players
     } else if (cards.len() == 1) && (cards[0].is_victory()) {
         // TODO: Reveal card to other players
         let player = arena.player_mut(player_id).unwrap();
-        let _ = player
+        let card = player
             .hand
             .move_card(&mut player.draw_pile, cards[0])
             .unwrap();
-        Ok(Outcome::None)
+        arena.log.emit(GameEvent::Reveal { player_id, card });
+        arena.log.emit(GameEvent::PutOnDeck { player_id, card });
     } else {
-        error
+        return error;
     }
+
+    Ok(reaction::respond(arena, player_id, EFFECT_B))
 }
 
 #[cfg(test)]
@@ -106,6 +126,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn reveal_nothing_while_immune() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.next_player_id();
+
+        let cards = [];
+
+        let player = arena.player_mut(player_id).unwrap();
+        player.hand.push(CardKind::Estate);
+        player.immune = true;
+
+        assert_eq!(
+            reveal_victory_card(&mut arena, player_id, &cards),
+            Ok(Outcome::None)
+        );
+    }
+
     #[test]
     fn reveal_non_victory_card() {
         let mut arena = test_util::setup_arena();
@@ -1,7 +1,17 @@
 use super::prelude::*;
 
-pub(super) const EFFECT: &Effect =
-    &Effect::Conditional(func, "Discard any number of cards, then draw that many.");
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    func,
+    &Decision {
+        description: "Discard any number of cards, then draw that many.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::Any,
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: true,
+    },
+);
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
     let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
@@ -10,13 +20,16 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
         return error;
     }
 
-    let player = arena.current_player_mut();
+    let player = &mut current_player!(arena);
+    let rng = &mut arena.rng;
+    let log = &mut arena.log;
     player
         .hand
         .move_all_cards(&mut player.discard_pile, cards)
         .map(|_| {
+            log.emit_all(cards, |card| GameEvent::Discard { player_id, card });
             for _ in cards {
-                player.draw_card();
+                player.draw_card(rng, log);
             }
             Outcome::None
         })
@@ -1,7 +1,17 @@
 use super::prelude::*;
 
-pub(super) const EFFECT: &Effect =
-    &Effect::Conditional(func, "Trash up to 4 cards from your hand.");
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    func,
+    &Decision {
+        description: "Trash up to 4 cards from your hand.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::UpTo(4),
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: true,
+    },
+);
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
     let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
@@ -14,7 +24,12 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
         current_player!(arena)
             .hand
             .move_all_cards(&mut arena.trash, cards)
-            .and(Ok(Outcome::None))
+            .map(|_| {
+                arena
+                    .log
+                    .emit_all(cards, |card| GameEvent::Trash { player_id, card });
+                Outcome::None
+            })
             .or(error)
     } else {
         error
@@ -0,0 +1,66 @@
+use super::prelude::*;
+
+// "Each other player draws a card."
+pub(super) const EFFECT: &Effect = &Effect::Unconditional(draw);
+
+fn draw(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    let current_player_id = arena.current_player_id;
+    let rng = &mut arena.rng;
+    let log = &mut arena.log;
+
+    for (player_id, player) in arena.players.iter_mut().enumerate() {
+        if player_id != current_player_id {
+            player.draw_card(rng, log);
+        }
+    }
+
+    Outcome::None
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::CardKind;
+
+    #[test]
+    fn other_player_draws_card() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        let hand_size = arena.current_player().hand.len();
+        let other_hand_size = arena.player(other_player_id).unwrap().hand.len();
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::CouncilRoom),
+            Outcome::None
+        );
+        assert_eq!(arena.current_player().hand.len(), hand_size);
+        assert_eq!(
+            arena.player(other_player_id).unwrap().hand.len(),
+            other_hand_size + 1
+        );
+    }
+
+    #[test]
+    fn other_player_draws_from_empty_deck() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        let player = arena.player_mut(other_player_id).unwrap();
+        player.draw_pile.clear();
+        player.discard_pile.clear();
+        let other_hand_size = player.hand.len();
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::CouncilRoom),
+            Outcome::None
+        );
+        assert_eq!(
+            arena.player(other_player_id).unwrap().hand.len(),
+            other_hand_size
+        );
+    }
+}
@@ -2,7 +2,15 @@ use super::prelude::*;
 
 pub(super) const EFFECT: &Effect = &Effect::Conditional(
     func,
-    "Look through your discard pile. You may put a card from it onto your deck.",
+    &Decision {
+        description: "Look through your discard pile. You may put a card from it onto your deck.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::DiscardPile,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: true,
+    },
 );
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
@@ -19,7 +27,10 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
         player
             .discard_pile
             .move_card(&mut player.draw_pile, cards[0])
-            .and(Ok(Outcome::None))
+            .map(|card| {
+                arena.log.emit(GameEvent::PutOnDeck { player_id, card });
+                Outcome::None
+            })
             .or(error)
     } else {
         error
@@ -0,0 +1,216 @@
+use super::prelude::*;
+
+// "Draw until you have 7 cards in hand, skipping any Action cards you choose
+// to; set those aside, discarding them afterwards."
+pub(super) const EFFECT: &Effect = &Effect::Unconditional(draw);
+
+fn draw(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    let player = &mut current_player!(arena);
+    let player_id = player.id;
+
+    while player.hand.len() < 7 {
+        match player.draw_card(&mut arena.rng, &mut arena.log) {
+            // Player must choose whether to skip the drawn Action card.
+            Some(card) if card.is_action() => return Outcome::Effect(SECONDARY_EFFECT),
+            Some(_) => (),
+            None => break,
+        }
+    }
+
+    // Discard the skipped Action cards.
+    arena
+        .log
+        .emit_all(&player.stage, |card| GameEvent::Discard { player_id, card });
+    player.discard_pile.append(&mut player.stage);
+
+    Outcome::None
+}
+
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    skip,
+    &Decision {
+        description: "You may set aside the drawn Action card, discarding it afterwards.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::DrawnCard,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Action,
+        max_cost: None,
+        optional: true,
+    },
+);
+
+fn skip(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(
+        &SECONDARY_EFFECT.description(),
+    ));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    // Player chooses to set aside the drawn card with cards: [drawn_card].
+    // Player chooses to keep the drawn card with cards: [].
+    // Other inputs are treated as errors.
+    if cards.is_empty() {
+        Ok(Outcome::Effect(EFFECT))
+    } else if (cards.len() == 1) && (cards[0] == *arena.current_player().hand.last().unwrap()) {
+        let player = arena.current_player_mut();
+        let card = player.hand.pop().unwrap();
+        player.stage.push(card);
+        arena.log.emit(GameEvent::SetAside { player_id, card });
+
+        Ok(Outcome::Effect(EFFECT))
+    } else {
+        error
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind};
+
+    fn setup_draw(hand_size: usize, draw_pile: &[CardKind]) -> Arena {
+        let mut arena = test_util::setup_arena();
+
+        let player = arena.current_player_mut();
+        player.hand.clear();
+        player.draw_pile.clear();
+        player.discard_pile.clear();
+        for _ in 0..hand_size {
+            player.hand.push(CardKind::Copper);
+        }
+        for &card in draw_pile.iter().rev() {
+            player.draw_pile.push(card);
+        }
+
+        arena
+    }
+
+    #[test]
+    fn draw_to_7_cards() {
+        let mut arena = setup_draw(5, &[CardKind::Silver, CardKind::Gold, CardKind::Estate]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::Library),
+            Outcome::None
+        );
+        assert_eq!(arena.current_player().hand.len(), 7);
+        assert_eq!(arena.current_player().draw_pile, cardvec![CardKind::Estate]);
+    }
+
+    #[test]
+    fn draw_with_7_cards_in_hand() {
+        let mut arena = setup_draw(7, &[CardKind::Silver]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::Library),
+            Outcome::None
+        );
+        assert_eq!(arena.current_player().hand.len(), 7);
+        assert_eq!(arena.current_player().draw_pile.len(), 1);
+    }
+
+    #[test]
+    fn draw_until_deck_is_empty() {
+        let mut arena = setup_draw(3, &[CardKind::Silver]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::Library),
+            Outcome::None
+        );
+        assert_eq!(arena.current_player().hand.len(), 4);
+    }
+
+    #[test]
+    fn draw_action_card() {
+        let mut arena = setup_draw(5, &[CardKind::Smithy, CardKind::Gold]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::Library),
+            Outcome::Effect(SECONDARY_EFFECT)
+        );
+        assert_eq!(arena.current_player().hand.last(), Some(&CardKind::Smithy));
+        assert_eq!(arena.current_player().draw_pile, cardvec![CardKind::Gold]);
+    }
+
+    #[test]
+    fn draw_discards_skipped_cards() {
+        let mut arena = setup_draw(6, &[CardKind::Gold]);
+        let ignored_player_id = arena.current_player_id;
+
+        arena.current_player_mut().stage.push(CardKind::Smithy);
+
+        assert_eq!(
+            draw(&mut arena, ignored_player_id, CardKind::Library),
+            Outcome::None
+        );
+        assert_eq!(arena.current_player().stage, cardvec![]);
+        assert_eq!(
+            arena.current_player().discard_pile,
+            cardvec![CardKind::Smithy]
+        );
+    }
+
+    #[test]
+    fn skip_drawn_action_card() {
+        let mut arena = setup_draw(5, &[]);
+        let player_id = arena.current_player_id;
+
+        arena.current_player_mut().hand.push(CardKind::Smithy);
+        let cards = [CardKind::Smithy];
+
+        assert_eq!(
+            skip(&mut arena, player_id, &cards),
+            Ok(Outcome::Effect(EFFECT))
+        );
+        assert_eq!(arena.current_player().hand.len(), 5);
+        assert_eq!(arena.current_player().stage, cardvec![CardKind::Smithy]);
+        assert_eq!(
+            arena.events().last(),
+            Some(&GameEvent::SetAside {
+                player_id,
+                card: CardKind::Smithy
+            })
+        );
+    }
+
+    #[test]
+    fn keep_drawn_action_card() {
+        let mut arena = setup_draw(5, &[]);
+        let player_id = arena.current_player_id;
+
+        arena.current_player_mut().hand.push(CardKind::Smithy);
+        let cards = [];
+
+        assert_eq!(
+            skip(&mut arena, player_id, &cards),
+            Ok(Outcome::Effect(EFFECT))
+        );
+        assert_eq!(arena.current_player().hand.len(), 6);
+        assert_eq!(arena.current_player().stage, cardvec![]);
+    }
+
+    #[test]
+    fn skip_wrong_card() {
+        let mut arena = setup_draw(5, &[]);
+        let player_id = arena.current_player_id;
+
+        arena.current_player_mut().hand.push(CardKind::Smithy);
+        let cards = [CardKind::Copper];
+
+        assert_eq!(
+            skip(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().stage, cardvec![]);
+    }
+}
@@ -0,0 +1,81 @@
+use super::prelude::*;
+
+// "The first time you play a Silver this turn, +$1."
+pub(super) const EFFECT: &Effect = &Effect::Unconditional(register);
+
+fn register(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    arena.register_trigger(TRIGGER);
+
+    Outcome::None
+}
+
+pub(super) const TRIGGER: &Trigger = &Trigger(first_silver);
+
+fn first_silver(arena: &mut Arena, card: CardKind) -> bool {
+    if card == CardKind::Silver {
+        // Treasures are only played during the buy phase.
+        arena.turn.as_buy_phase_mut().unwrap().remaining_copper += 1;
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::turn::{self, Turn};
+    use crate::dominion::CardKind;
+
+    #[test]
+    fn register_trigger() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let ignored_card = CardKind::Merchant;
+
+        assert_eq!(
+            register(&mut arena, ignored_player_id, ignored_card),
+            Outcome::None
+        );
+        assert_eq!(arena.triggers, vec![TRIGGER]);
+    }
+
+    #[test]
+    fn play_copper_does_not_fire() {
+        let mut arena = test_util::setup_arena();
+
+        arena.turn = Turn::Buy(turn::BuyPhase {
+            remaining_buys: 1,
+            remaining_copper: 0,
+        });
+
+        assert!(!first_silver(&mut arena, CardKind::Copper));
+        assert_eq!(
+            arena.turn,
+            Turn::Buy(turn::BuyPhase {
+                remaining_buys: 1,
+                remaining_copper: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn play_silver_fires() {
+        let mut arena = test_util::setup_arena();
+
+        arena.turn = Turn::Buy(turn::BuyPhase {
+            remaining_buys: 1,
+            remaining_copper: 0,
+        });
+
+        assert!(first_silver(&mut arena, CardKind::Silver));
+        assert_eq!(
+            arena.turn,
+            Turn::Buy(turn::BuyPhase {
+                remaining_buys: 1,
+                remaining_copper: 1,
+            })
+        );
+    }
+}
@@ -1,8 +1,17 @@
 use super::prelude::*;
+use super::reaction;
 
 pub(super) const EFFECT: &Effect = &Effect::Conditional(
     func,
-    "Each other player discards down to 3 cards in their hand.",
+    &Decision {
+        description: "Each other player discards down to 3 cards in their hand.",
+        chooser: Chooser::OtherPlayers,
+        zone: Zone::Hand,
+        count: CardCount::DownTo(3),
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: false,
+    },
 );
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
@@ -12,20 +21,37 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
         return error;
     }
 
-    let hand = &arena.player(player_id).unwrap().hand;
-
-    if (hand.len() <= 3) && cards.is_empty() {
-        Ok(Outcome::None)
+    let player = match arena.player(player_id) {
+        // Each other player discards only once.
+        Ok(player) if !player.responded => player,
+        _ => return error,
+    };
+    let hand = &player.hand;
+
+    if player.immune {
+        // Player revealed a Reaction and is unaffected.
+        if !cards.is_empty() {
+            return error;
+        }
+    } else if (hand.len() <= 3) && cards.is_empty() {
+        // Player already has 3 or fewer cards in hand.
     } else if hand.len() == (cards.len() + 3) {
         let player = arena.player_mut(player_id).unwrap();
-        player
+        if player
             .hand
             .move_all_cards(&mut player.discard_pile, cards)
-            .and(Ok(Outcome::None))
-            .or(error)
+            .is_err()
+        {
+            return error;
+        }
+        arena
+            .log
+            .emit_all(cards, |card| GameEvent::Discard { player_id, card });
     } else {
-        error
+        return error;
     }
+
+    Ok(reaction::respond(arena, player_id, EFFECT))
 }
 
 #[cfg(test)]
@@ -33,7 +59,7 @@ mod test {
     use super::super::test_util;
     use super::*;
     use crate::dominion::types::Error;
-    use crate::dominion::CardKind;
+    use crate::dominion::{CardKind, KingdomSet};
 
     #[test]
     fn other_player_0_cards_in_hand_discard_0() {
@@ -131,6 +157,37 @@ mod test {
         assert_eq!(func(&mut arena, player_id, &cards), Ok(Outcome::None));
     }
 
+    #[test]
+    fn other_player_immune_discard_0() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.next_player_id();
+
+        arena.player_mut(player_id).unwrap().immune = true;
+        let cards = [];
+
+        assert_eq!(arena.player(player_id).unwrap().hand.len(), 5);
+        assert_eq!(func(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(arena.player(player_id).unwrap().hand.len(), 5);
+    }
+
+    #[test]
+    fn other_player_immune_discard_2() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.next_player_id();
+
+        arena.player_mut(player_id).unwrap().immune = true;
+        let cards = [
+            arena.player(player_id).unwrap().hand[0],
+            arena.player(player_id).unwrap().hand[1],
+        ];
+
+        assert_eq!(
+            func(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.player(player_id).unwrap().hand.len(), 5);
+    }
+
     #[test]
     fn other_player_cards_not_in_hand() {
         let mut arena = test_util::setup_arena();
@@ -174,4 +231,30 @@ mod test {
             Err(Error::UnresolvedActionEffect(EFFECT.description()))
         );
     }
+
+    #[test]
+    fn each_other_player_discards_once() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 3, 1);
+        arena.current_player_mut().hand.push(CardKind::Militia);
+        for player in &mut arena.players {
+            player.hand.retain(|card| !card.is_reaction());
+        }
+
+        assert!(arena.play_card(CardKind::Militia).is_err());
+
+        let hand = arena.players[1].hand.clone();
+        assert_eq!(
+            arena.select_cards(1, &hand[..2]),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.players[1].hand.len(), 3);
+        assert_eq!(arena.legal_moves(1), Ok(vec![]));
+        assert!(arena.select_cards(1, &hand[2..3]).is_err());
+        assert_eq!(arena.players[1].hand.len(), 3);
+
+        let hand = arena.players[2].hand.clone();
+        assert_eq!(arena.select_cards(2, &hand[..2]), Ok(()));
+        assert_eq!(arena.players[2].hand.len(), 3);
+        assert_eq!(arena.pending_decision(), None);
+    }
 }
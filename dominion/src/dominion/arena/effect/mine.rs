@@ -0,0 +1,265 @@
+use super::prelude::*;
+
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    trash,
+    &Decision {
+        description: "You may trash a Treasure from your hand.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Treasure,
+        max_cost: None,
+        optional: true,
+    },
+);
+
+fn trash(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    if cards.is_empty() {
+        Ok(Outcome::None)
+    } else if (cards.len() == 1) && cards[0].is_treasure() {
+        current_player!(arena)
+            .hand
+            .move_card(&mut arena.trash, cards[0])
+            .map(|card| {
+                arena.log.emit(GameEvent::Trash { player_id, card });
+                Outcome::Effect(SECONDARY_EFFECT)
+            })
+            .or(error)
+    } else {
+        error
+    }
+}
+
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    gain,
+    &Decision {
+        description:
+            "Gain a Treasure to your hand costing up to $3 more than the trashed Treasure.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Supply,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Treasure,
+        max_cost: Some(CostBound::UpToTrashedPlus(3)),
+        optional: false,
+    },
+);
+
+fn gain(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(
+        &SECONDARY_EFFECT.description(),
+    ));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    // The trashed card is always on top of the trash as it is moved there by
+    // the primary effect.
+    let max_cost = arena.trash.last().unwrap().cost() + 3;
+
+    if cards.is_empty() {
+        if arena
+            .supply
+            .iter()
+            .any(|(card, &count)| card.is_treasure() && (card.cost() <= max_cost) && (count > 0))
+        {
+            // Player could have selected a card but didn't.
+            error
+        } else {
+            Ok(Outcome::None)
+        }
+    } else if (cards.len() == 1) && cards[0].is_treasure() && (cards[0].cost() <= max_cost) {
+        arena
+            .supply
+            .move_card(&mut current_player!(arena).hand, cards[0])
+            .map(|card| {
+                arena.log.emit(GameEvent::Gain { player_id, card });
+                Outcome::None
+            })
+            .or(error)
+    } else {
+        error
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind};
+
+    #[test]
+    fn trash_nothing() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let hand_size = arena.current_player().hand.len();
+        let cards = [];
+
+        assert_eq!(trash(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(arena.current_player().hand.len(), hand_size);
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_treasure() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Silver];
+
+        arena.current_player_mut().hand.push(cards[0]);
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Ok(Outcome::Effect(SECONDARY_EFFECT))
+        );
+        assert!(!arena.current_player().hand.contains(&cards[0]));
+        assert_eq!(arena.trash, cardvec![CardKind::Silver]);
+    }
+
+    #[test]
+    fn trash_non_treasure() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Estate];
+
+        arena.current_player_mut().hand.push(cards[0]);
+
+        assert!(!cards[0].is_treasure());
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_treasure_not_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert!(!arena.current_player().hand.contains(&cards[0]));
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_multiple_treasures() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Copper, CardKind::Copper];
+
+        arena.current_player_mut().hand.push(CardKind::Copper);
+        arena.current_player_mut().hand.push(CardKind::Copper);
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    fn setup_gain(trashed_card: CardKind) -> Arena {
+        let mut arena = test_util::setup_arena();
+
+        arena.current_player_mut().hand.clear();
+        arena.trash.push(trashed_card);
+
+        arena
+    }
+
+    #[test]
+    fn gain_valid_treasure_to_hand() {
+        let mut arena = setup_gain(CardKind::Silver);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert!(cards[0].cost() <= CardKind::Silver.cost() + 3);
+        assert_eq!(gain(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(arena.current_player().hand, cardvec![CardKind::Gold]);
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn gain_treasure_invalid_cost() {
+        let mut arena = setup_gain(CardKind::Copper);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert!(cards[0].cost() > CardKind::Copper.cost() + 3);
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().hand, cardvec![]);
+    }
+
+    #[test]
+    fn gain_non_treasure() {
+        let mut arena = setup_gain(CardKind::Silver);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Smithy];
+
+        assert!(cards[0].cost() <= CardKind::Silver.cost() + 3);
+        assert!(!cards[0].is_treasure());
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().hand, cardvec![]);
+    }
+
+    #[test]
+    fn gain_no_treasure_with_valid_candidates() {
+        let mut arena = setup_gain(CardKind::Copper);
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+    }
+
+    #[test]
+    fn gain_no_treasure_without_valid_candidates() {
+        let mut arena = setup_gain(CardKind::Copper);
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        arena.supply.iter_mut().for_each(|(card, count)| {
+            if card.is_treasure() {
+                *count = 0;
+            }
+        });
+
+        assert_eq!(gain(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(arena.current_player().hand, cardvec![]);
+    }
+}
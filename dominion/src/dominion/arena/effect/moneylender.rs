@@ -2,7 +2,15 @@ use super::prelude::*;
 
 pub(super) const EFFECT: &Effect = &Effect::Conditional(
     func,
-    "You may trash a Copper from your hand. If you do, +$3.",
+    &Decision {
+        description: "You may trash a Copper from your hand. If you do, +$3.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Card(CardKind::Copper),
+        max_cost: None,
+        optional: true,
+    },
 );
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
@@ -18,7 +26,8 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
         current_player!(arena)
             .hand
             .move_card(&mut arena.trash, cards[0])
-            .map(|_| {
+            .map(|card| {
+                arena.log.emit(GameEvent::Trash { player_id, card });
                 arena.turn.as_action_phase_mut().unwrap().remaining_copper += 3;
                 Outcome::None
             })
@@ -10,8 +10,18 @@ fn check_discard(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
     }
 }
 
-pub(super) const SECONDARY_EFFECT: &Effect =
-    &Effect::Conditional(discard, "Discard a card per empty Supply pile.");
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    discard,
+    &Decision {
+        description: "Discard a card per empty Supply pile.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::PerEmptySupplyPile,
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: false,
+    },
+);
 
 fn discard(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
     let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
@@ -25,7 +35,12 @@ fn discard(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Ou
         player
             .hand
             .move_all_cards(&mut player.discard_pile, cards)
-            .and(Ok(Outcome::None))
+            .map(|_| {
+                arena
+                    .log
+                    .emit_all(cards, |card| GameEvent::Discard { player_id, card });
+                Outcome::None
+            })
             .or(error)
     } else {
         error
@@ -49,7 +64,7 @@ mod test {
 
     impl Supply {
         fn empty(&mut self, card: CardKind) {
-            let (_, supply_count) = self.find(card).and_then(|i| self.get_entry_mut(i)).unwrap();
+            let (_, supply_count) = self.iter_mut().find(|&(k, _)| k == card).unwrap();
 
             *supply_count = 0;
         }
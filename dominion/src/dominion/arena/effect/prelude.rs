@@ -8,9 +8,11 @@
 //! use super::prelude::*;
 //! ```
 pub(super) use crate::dominion::arena::effect::{CardActionQueue, Effect, Outcome};
-pub(super) use crate::dominion::location::Location;
+pub(super) use crate::dominion::arena::trigger::Trigger;
+pub(super) use crate::dominion::decision::{
+    CardCount, CardFilter, Chooser, CostBound, Decision, Zone,
+};
+pub(super) use crate::dominion::event::GameEvent;
+pub(super) use crate::dominion::location::{CardVec, Location};
 pub(super) use crate::dominion::types::{Error, Result};
 pub(super) use crate::dominion::{Arena, CardKind};
-
-#[cfg(test)]
-pub(super) use crate::dominion::location::CardVec;
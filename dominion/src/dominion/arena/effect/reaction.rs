@@ -0,0 +1,257 @@
+use super::prelude::*;
+
+// When an Attack card is played, each other player with a Reaction card in
+// hand may reveal it before the attack resolves.
+pub(super) const EFFECT: &Effect = &Effect::Unconditional(open_window);
+
+fn open_window(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    let current_player_id = arena.current_player_id;
+
+    for (player_id, player) in arena.players.iter_mut().enumerate() {
+        player.immune = false;
+        player.responded = false;
+        player.reacting =
+            (player_id != current_player_id) && player.hand.iter().any(|card| card.is_reaction());
+    }
+
+    if arena.players.iter().any(|player| player.reacting) {
+        Outcome::Effect(SECONDARY_EFFECT)
+    } else {
+        Outcome::None
+    }
+}
+
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    reveal,
+    &Decision {
+        description: "Each other player may reveal a Reaction card from their hand.",
+        chooser: Chooser::OtherPlayers,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Reaction,
+        max_cost: None,
+        optional: true,
+    },
+);
+
+fn reveal(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(
+        &SECONDARY_EFFECT.description(),
+    ));
+
+    let player = match arena.players.get_mut(player_id) {
+        Some(player) if player.reacting => player,
+        _ => return error,
+    };
+
+    if cards.is_empty() {
+        // Player chooses not to reveal a Reaction card.
+    } else if (cards.len() == 1) && cards[0].is_reaction() && player.hand.contains(&cards[0]) {
+        // TODO: Reveal card to other players
+        // Moat is the only Reaction card in the base set.
+        player.immune = true;
+        arena.log.emit(GameEvent::Reveal {
+            player_id,
+            card: cards[0],
+        });
+    } else {
+        return error;
+    }
+
+    player.reacting = false;
+
+    if arena.players.iter().any(|player| player.reacting) {
+        // Wait for the remaining players to respond.
+        Ok(Outcome::Effect(SECONDARY_EFFECT))
+    } else {
+        Ok(Outcome::None)
+    }
+}
+
+/// Records that `player_id` made their selection for the attack being played,
+/// and returns `effect` again while other players have yet to make theirs.
+pub(super) fn respond(arena: &mut Arena, player_id: usize, effect: &'static Effect) -> Outcome {
+    arena.players[player_id].responded = true;
+
+    let current_player_id = arena.current_player_id;
+    let waiting = arena
+        .players
+        .iter()
+        .enumerate()
+        .any(|(id, player)| (id != current_player_id) && !player.responded);
+
+    if waiting {
+        Outcome::Effect(effect)
+    } else {
+        Outcome::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind, KingdomSet};
+
+    #[test]
+    fn open_window_no_reaction_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            open_window(&mut arena, ignored_player_id, CardKind::Militia),
+            Outcome::None
+        );
+        assert!(arena.players.iter().all(|player| !player.reacting));
+    }
+
+    #[test]
+    fn open_window_reaction_in_current_player_hand() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+
+        arena.current_player_mut().hand.push(CardKind::Moat);
+
+        assert_eq!(
+            open_window(&mut arena, ignored_player_id, CardKind::Militia),
+            Outcome::None
+        );
+    }
+
+    #[test]
+    fn open_window_reaction_in_other_player_hand() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        arena
+            .player_mut(other_player_id)
+            .unwrap()
+            .hand
+            .push(CardKind::Moat);
+
+        assert_eq!(
+            open_window(&mut arena, ignored_player_id, CardKind::Militia),
+            Outcome::Effect(SECONDARY_EFFECT)
+        );
+        assert!(arena.player(other_player_id).unwrap().reacting);
+    }
+
+    #[test]
+    fn open_window_resets_immunity() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        arena.player_mut(other_player_id).unwrap().immune = true;
+
+        assert_eq!(
+            open_window(&mut arena, ignored_player_id, CardKind::Militia),
+            Outcome::None
+        );
+        assert!(!arena.player(other_player_id).unwrap().immune);
+    }
+
+    fn setup_reveal() -> (Arena, usize) {
+        let mut arena = test_util::setup_arena();
+        let other_player_id = arena.next_player_id();
+
+        let player = arena.player_mut(other_player_id).unwrap();
+        player.hand.push(CardKind::Moat);
+        player.reacting = true;
+
+        (arena, other_player_id)
+    }
+
+    #[test]
+    fn reveal_reaction() {
+        let (mut arena, player_id) = setup_reveal();
+
+        let cards = [CardKind::Moat];
+
+        assert_eq!(reveal(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert!(arena.player(player_id).unwrap().immune);
+        assert!(!arena.player(player_id).unwrap().reacting);
+
+        // Revealed cards stay in hand.
+        assert!(arena.player(player_id).unwrap().hand.contains(&cards[0]));
+    }
+
+    #[test]
+    fn reveal_nothing() {
+        let (mut arena, player_id) = setup_reveal();
+
+        let cards = [];
+
+        assert_eq!(reveal(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert!(!arena.player(player_id).unwrap().immune);
+        assert!(!arena.player(player_id).unwrap().reacting);
+    }
+
+    #[test]
+    fn reveal_non_reaction() {
+        let (mut arena, player_id) = setup_reveal();
+
+        let cards = [CardKind::Copper];
+
+        arena.player_mut(player_id).unwrap().hand.push(cards[0]);
+
+        assert!(!cards[0].is_reaction());
+        assert_eq!(
+            reveal(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert!(!arena.player(player_id).unwrap().immune);
+    }
+
+    #[test]
+    fn reveal_reaction_not_in_hand() {
+        let (mut arena, player_id) = setup_reveal();
+
+        let cards = [CardKind::Moat];
+
+        arena.player_mut(player_id).unwrap().hand.clear();
+
+        assert_eq!(
+            reveal(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert!(!arena.player(player_id).unwrap().immune);
+    }
+
+    #[test]
+    fn reveal_by_player_not_reacting() {
+        let (mut arena, _) = setup_reveal();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        assert_eq!(
+            reveal(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+    }
+
+    #[test]
+    fn reveal_with_other_players_reacting() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 3, 1);
+
+        arena.player_mut(1).unwrap().reacting = true;
+        arena.player_mut(2).unwrap().reacting = true;
+
+        let cards = [];
+
+        assert_eq!(
+            reveal(&mut arena, 1, &cards),
+            Ok(Outcome::Effect(SECONDARY_EFFECT))
+        );
+        assert_eq!(reveal(&mut arena, 2, &cards), Ok(Outcome::None));
+    }
+}
@@ -0,0 +1,303 @@
+use super::prelude::*;
+
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    trash,
+    &Decision {
+        description: "Trash a card from your hand.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: false,
+    },
+);
+
+fn trash(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    if cards.is_empty() {
+        if arena.current_player().hand.is_empty() {
+            // Nothing can be trashed so nothing can be gained.
+            Ok(Outcome::None)
+        } else {
+            // Player must trash a card but didn't.
+            error
+        }
+    } else if cards.len() == 1 {
+        current_player!(arena)
+            .hand
+            .move_card(&mut arena.trash, cards[0])
+            .map(|card| {
+                arena.log.emit(GameEvent::Trash { player_id, card });
+                Outcome::Effect(SECONDARY_EFFECT)
+            })
+            .or(error)
+    } else {
+        error
+    }
+}
+
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+    gain,
+    &Decision {
+        description: "Gain a card costing up to $2 more than the trashed card.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Supply,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Any,
+        max_cost: Some(CostBound::UpToTrashedPlus(2)),
+        optional: false,
+    },
+);
+
+fn gain(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(
+        &SECONDARY_EFFECT.description(),
+    ));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    // The trashed card is always on top of the trash as it is moved there by
+    // the primary effect.
+    let max_cost = arena.trash.last().unwrap().cost() + 2;
+
+    if cards.is_empty() {
+        if arena
+            .supply
+            .iter()
+            .any(|(card, &count)| (card.cost() <= max_cost) && (count > 0))
+        {
+            // Player could have selected a card but didn't.
+            error
+        } else {
+            Ok(Outcome::None)
+        }
+    } else if (cards.len() == 1) && (cards[0].cost() <= max_cost) {
+        arena
+            .supply
+            .move_card(&mut current_player!(arena).discard_pile, cards[0])
+            .map(|card| {
+                arena.log.emit(GameEvent::Gain { player_id, card });
+                Outcome::None
+            })
+            .or(error)
+    } else {
+        error
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind};
+
+    #[test]
+    fn trash_card_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Estate];
+
+        arena.current_player_mut().hand.push(cards[0]);
+        let hand_size = arena.current_player().hand.len();
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Ok(Outcome::Effect(SECONDARY_EFFECT))
+        );
+        assert_eq!(arena.current_player().hand.len(), hand_size - 1);
+        assert_eq!(arena.trash, cardvec![CardKind::Estate]);
+        assert_eq!(
+            arena.log.events.last(),
+            Some(&GameEvent::Trash {
+                player_id,
+                card: CardKind::Estate
+            })
+        );
+    }
+
+    #[test]
+    fn trash_card_not_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Gold];
+
+        assert!(!arena.current_player().hand.contains(&cards[0]));
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_nothing_with_cards_in_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        assert!(!arena.current_player().hand.is_empty());
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+    }
+
+    #[test]
+    fn trash_nothing_with_empty_hand() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        arena.current_player_mut().hand.clear();
+
+        assert_eq!(trash(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_multiple_cards() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.current_player_id;
+
+        let hand = &arena.current_player().hand;
+        let cards = [hand[0], hand[1]];
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+        assert_eq!(arena.trash, cardvec![]);
+    }
+
+    #[test]
+    fn trash_by_other_player() {
+        let mut arena = test_util::setup_arena();
+        let player_id = arena.next_player_id();
+
+        let cards = [arena.player(player_id).unwrap().hand[0]];
+
+        assert_eq!(
+            trash(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(EFFECT.description()))
+        );
+    }
+
+    fn setup_gain(trashed_card: CardKind) -> Arena {
+        let mut arena = test_util::setup_arena();
+
+        arena.trash.push(trashed_card);
+
+        arena
+    }
+
+    #[test]
+    fn gain_valid_card() {
+        let mut arena = setup_gain(CardKind::Silver);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Market];
+
+        assert_eq!(cards[0].cost(), CardKind::Silver.cost() + 2);
+        assert_eq!(gain(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(
+            arena.current_player().discard_pile,
+            cardvec![CardKind::Market]
+        );
+    }
+
+    #[test]
+    fn gain_card_invalid_cost() {
+        let mut arena = setup_gain(CardKind::Copper);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Silver];
+
+        assert!(cards[0].cost() > CardKind::Copper.cost() + 2);
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn gain_card_not_in_supply() {
+        let mut arena = setup_gain(CardKind::Gold);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Witch];
+
+        assert!(cards[0].cost() <= CardKind::Gold.cost() + 2);
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn gain_no_card_with_valid_candidates() {
+        let mut arena = setup_gain(CardKind::Estate);
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn gain_no_card_without_valid_candidates() {
+        let mut arena = setup_gain(CardKind::Estate);
+        let player_id = arena.current_player_id;
+
+        let cards = [];
+
+        arena.supply.iter_mut().for_each(|(card, count)| {
+            if card.cost() <= 4 {
+                *count = 0;
+            }
+        });
+
+        assert_eq!(gain(&mut arena, player_id, &cards), Ok(Outcome::None));
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn gain_multiple_cards() {
+        let mut arena = setup_gain(CardKind::Estate);
+        let player_id = arena.current_player_id;
+
+        let cards = [CardKind::Copper, CardKind::Copper];
+
+        assert_eq!(
+            gain(&mut arena, player_id, &cards),
+            Err(Error::UnresolvedActionEffect(
+                SECONDARY_EFFECT.description()
+            ))
+        );
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+}
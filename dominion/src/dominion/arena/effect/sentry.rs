@@ -0,0 +1,315 @@
+use super::prelude::*;
+
+// "Look at the top 2 cards of your deck."
+pub(super) const EFFECT: &Effect = &Effect::Unconditional(look);
+
+fn look(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    let player = &mut current_player!(arena);
+
+    // The cards being looked at are kept in the stage.
+    for _ in 0..2 {
+        player.stage_card(&mut arena.rng, &mut arena.log);
+    }
+
+    if player.stage.is_empty() {
+        Outcome::None
+    } else {
+        Outcome::Effect(TRASH_EFFECT)
+    }
+}
+
+pub(super) const TRASH_EFFECT: &Effect = &Effect::Conditional(
+    trash,
+    &Decision {
+        description: "Trash any number of the top 2 cards of your deck.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Stage,
+        count: CardCount::Any,
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: true,
+    },
+);
+
+fn trash(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(&TRASH_EFFECT.description()));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    if cards.is_empty() {
+        Ok(Outcome::Effect(DISCARD_EFFECT))
+    } else {
+        let player = &mut current_player!(arena);
+        let log = &mut arena.log;
+
+        player
+            .stage
+            .move_all_cards(&mut arena.trash, cards)
+            .map(|_| {
+                log.emit_all(cards, |card| GameEvent::Trash { player_id, card });
+
+                if player.stage.is_empty() {
+                    Outcome::None
+                } else {
+                    Outcome::Effect(DISCARD_EFFECT)
+                }
+            })
+            .or(error)
+    }
+}
+
+pub(super) const DISCARD_EFFECT: &Effect = &Effect::Conditional(
+    discard,
+    &Decision {
+        description: "Discard any number of the remaining top 2 cards of your deck.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Stage,
+        count: CardCount::Any,
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: true,
+    },
+);
+
+fn discard(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(&DISCARD_EFFECT.description()));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    let player = &mut current_player!(arena);
+    let log = &mut arena.log;
+    player
+        .stage
+        .move_all_cards(&mut player.discard_pile, cards)
+        .map(|_| {
+            log.emit_all(cards, |card| GameEvent::Discard { player_id, card });
+
+            match player.stage.len() {
+                0 => Outcome::None,
+                1 => {
+                    // There is only one order to put back a single card.
+                    log.emit_all(&player.stage, |card| GameEvent::PutOnDeck {
+                        player_id,
+                        card,
+                    });
+                    player.draw_pile.append(&mut player.stage);
+                    Outcome::None
+                }
+                _ => Outcome::Effect(ORDER_EFFECT),
+            }
+        })
+        .or(error)
+}
+
+pub(super) const ORDER_EFFECT: &Effect = &Effect::Conditional(
+    order,
+    &Decision {
+        description: "Put the rest back on top in any order, starting with the top card.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Stage,
+        count: CardCount::All,
+        filter: CardFilter::Any,
+        max_cost: None,
+        optional: false,
+    },
+);
+
+fn order(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
+    let error = Err(Error::UnresolvedActionEffect(&ORDER_EFFECT.description()));
+
+    if player_id != arena.current_player_id {
+        return error;
+    }
+
+    let player = &mut current_player!(arena);
+
+    // Every card looked at must be put back.
+    if (cards.len() != player.stage.len())
+        || player
+            .stage
+            .move_all_cards(&mut CardVec::new(), cards)
+            .is_err()
+    {
+        return error;
+    }
+
+    // The top of the draw pile is the end of the vector, so the cards are put
+    // back in reverse order.
+    for &card in cards.iter().rev() {
+        player.draw_pile.push(card);
+        arena.log.emit(GameEvent::PutOnDeck { player_id, card });
+    }
+
+    Ok(Outcome::None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind};
+
+    fn setup_look(draw_pile: &[CardKind]) -> Arena {
+        let mut arena = test_util::setup_arena();
+
+        let player = arena.current_player_mut();
+        player.draw_pile.clear();
+        player.discard_pile.clear();
+        for &card in draw_pile.iter().rev() {
+            player.draw_pile.push(card);
+        }
+
+        arena
+    }
+
+    #[test]
+    fn look_at_top_2_cards() {
+        let mut arena = setup_look(&[CardKind::Gold, CardKind::Curse, CardKind::Estate]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            look(&mut arena, ignored_player_id, CardKind::Sentry),
+            Outcome::Effect(TRASH_EFFECT)
+        );
+        assert_eq!(
+            arena.current_player().stage,
+            CardVec(vec![CardKind::Gold, CardKind::Curse])
+        );
+        assert_eq!(arena.current_player().draw_pile, cardvec![CardKind::Estate]);
+    }
+
+    #[test]
+    fn look_at_empty_deck() {
+        let mut arena = setup_look(&[]);
+        let ignored_player_id = arena.current_player_id;
+
+        assert_eq!(
+            look(&mut arena, ignored_player_id, CardKind::Sentry),
+            Outcome::None
+        );
+    }
+
+    fn setup_stage(stage: &[CardKind]) -> Arena {
+        let mut arena = setup_look(&[]);
+
+        for &card in stage {
+            arena.current_player_mut().stage.push(card);
+        }
+
+        arena
+    }
+
+    #[test]
+    fn trash_nothing() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Curse]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            trash(&mut arena, player_id, &[]),
+            Ok(Outcome::Effect(DISCARD_EFFECT))
+        );
+        assert_eq!(arena.current_player().stage.len(), 2);
+    }
+
+    #[test]
+    fn trash_one_card() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Curse]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            trash(&mut arena, player_id, &[CardKind::Curse]),
+            Ok(Outcome::Effect(DISCARD_EFFECT))
+        );
+        assert_eq!(arena.current_player().stage, cardvec![CardKind::Gold]);
+        assert_eq!(arena.trash, cardvec![CardKind::Curse]);
+    }
+
+    #[test]
+    fn trash_all_cards() {
+        let mut arena = setup_stage(&[CardKind::Estate, CardKind::Curse]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            trash(&mut arena, player_id, &[CardKind::Estate, CardKind::Curse]),
+            Ok(Outcome::None)
+        );
+        assert_eq!(arena.current_player().stage, cardvec![]);
+        assert_eq!(arena.trash.len(), 2);
+    }
+
+    #[test]
+    fn trash_card_not_looked_at() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Curse]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            trash(&mut arena, player_id, &[CardKind::Copper]),
+            Err(Error::UnresolvedActionEffect(TRASH_EFFECT.description()))
+        );
+        assert_eq!(arena.current_player().stage.len(), 2);
+    }
+
+    #[test]
+    fn discard_one_card_and_put_back_other() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Estate]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            discard(&mut arena, player_id, &[CardKind::Estate]),
+            Ok(Outcome::None)
+        );
+        assert_eq!(arena.current_player().stage, cardvec![]);
+        assert_eq!(
+            arena.current_player().discard_pile,
+            cardvec![CardKind::Estate]
+        );
+        assert_eq!(arena.current_player().draw_pile, cardvec![CardKind::Gold]);
+    }
+
+    #[test]
+    fn discard_nothing() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Silver]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            discard(&mut arena, player_id, &[]),
+            Ok(Outcome::Effect(ORDER_EFFECT))
+        );
+        assert_eq!(arena.current_player().stage.len(), 2);
+    }
+
+    #[test]
+    fn order_cards() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Silver]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            order(&mut arena, player_id, &[CardKind::Silver, CardKind::Gold]),
+            Ok(Outcome::None)
+        );
+        assert_eq!(arena.current_player().stage, cardvec![]);
+
+        // Silver is on top of the draw pile.
+        assert_eq!(
+            arena.current_player().draw_pile,
+            CardVec(vec![CardKind::Gold, CardKind::Silver])
+        );
+    }
+
+    #[test]
+    fn order_missing_card() {
+        let mut arena = setup_stage(&[CardKind::Gold, CardKind::Silver]);
+        let player_id = arena.current_player_id;
+
+        assert_eq!(
+            order(&mut arena, player_id, &[CardKind::Silver]),
+            Err(Error::UnresolvedActionEffect(ORDER_EFFECT.description()))
+        );
+        assert_eq!(arena.current_player().stage.len(), 2);
+    }
+}
@@ -4,7 +4,7 @@ use crate::dominion::{Arena, KingdomSet};
 #[cfg(test)]
 pub(super) fn setup_arena_actions() -> (Arena, CardActionQueue) {
     let mut arena = Arena::new(KingdomSet::FirstGame, 2);
-    let actions = arena.actions.take().unwrap();
+    let actions = std::mem::replace(&mut arena.actions, CardActionQueue::new());
 
     (arena, actions)
 }
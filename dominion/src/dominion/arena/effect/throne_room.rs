@@ -1,7 +1,17 @@
 use super::prelude::*;
 
-pub(super) const EFFECT: &Effect =
-    &Effect::Conditional(func, "You may play an Action card from your hand twice.");
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    func,
+    &Decision {
+        description: "You may play an Action card from your hand twice.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Hand,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Action,
+        max_cost: None,
+        optional: true,
+    },
+);
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
     let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
@@ -18,6 +28,9 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
             .hand
             .move_card(&mut player.play_zone, cards[0])
             .map(|card| {
+                arena.log.emit(GameEvent::Play { player_id, card });
+                arena.log.emit(GameEvent::Play { player_id, card });
+
                 let mut actions = CardActionQueue::from_card(card);
                 actions.add_card(card);
                 Outcome::Actions(actions)
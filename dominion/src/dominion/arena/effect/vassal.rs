@@ -10,12 +10,13 @@ fn discard(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
 
     top_index
         .map(|x| {
-            let player = arena.current_player_mut();
+            let player = &mut current_player!(arena);
+            let player_id = player.id;
             player
                 .draw_pile
                 .move_index(&mut player.discard_pile, x)
                 .map(|card| {
-                    println!("discarded card");
+                    arena.log.emit(GameEvent::Discard { player_id, card });
                     if card.is_action() {
                         Outcome::Effect(SECONDARY_EFFECT)
                     } else {
@@ -28,9 +29,17 @@ fn discard(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
 }
 
 #[allow(clippy::non_ascii_literal)]
-const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
+pub(super) const SECONDARY_EFFECT: &Effect = &Effect::Conditional(
     select,
-    "Discard the top card of your deck. If it’s an Action card, you may play it.",
+    &Decision {
+        description: "Discard the top card of your deck. If it’s an Action card, you may play it.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::TopOfDiscardPile,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Action,
+        max_cost: None,
+        optional: true,
+    },
 );
 
 fn select(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
@@ -60,6 +69,10 @@ fn select(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Out
     }
 
     if play_card {
+        arena.log.emit(GameEvent::Play {
+            player_id,
+            card: cards[0],
+        });
         Ok(Outcome::Actions(CardActionQueue::from_card(cards[0])))
     } else {
         Ok(Outcome::None)
@@ -0,0 +1,115 @@
+use super::prelude::*;
+
+// "Each other player gains a Curse card."
+pub(super) const EFFECT: &Effect = &Effect::Unconditional(curse);
+
+fn curse(arena: &mut Arena, _: usize, _: CardKind) -> Outcome {
+    let num_players = arena.players.len();
+
+    // Curses are gained in turn order, starting with the next player.
+    for i in 1..num_players {
+        let player = &mut arena.players[(arena.current_player_id + i) % num_players];
+
+        if !player.immune {
+            // This fails once the Curse pile is empty but it doesn't matter.
+            if let Ok(card) = arena
+                .supply
+                .move_card(&mut player.discard_pile, CardKind::Curse)
+            {
+                arena.log.emit(GameEvent::Gain {
+                    player_id: player.id,
+                    card,
+                });
+            }
+        }
+    }
+
+    Outcome::None
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_util;
+    use super::*;
+    use crate::dominion::{CardKind, KingdomSet};
+
+    #[test]
+    fn other_player_gains_curse() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        assert_eq!(
+            curse(&mut arena, ignored_player_id, CardKind::Witch),
+            Outcome::None
+        );
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+        assert_eq!(
+            arena.player(other_player_id).unwrap().discard_pile,
+            cardvec![CardKind::Curse]
+        );
+    }
+
+    #[test]
+    fn immune_player_does_not_gain_curse() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        arena.player_mut(other_player_id).unwrap().immune = true;
+
+        assert_eq!(
+            curse(&mut arena, ignored_player_id, CardKind::Witch),
+            Outcome::None
+        );
+        assert_eq!(
+            arena.player(other_player_id).unwrap().discard_pile,
+            cardvec![]
+        );
+    }
+
+    #[test]
+    fn empty_curse_pile() {
+        let mut arena = test_util::setup_arena();
+        let ignored_player_id = arena.current_player_id;
+        let other_player_id = arena.next_player_id();
+
+        arena.supply.iter_mut().for_each(|(card, count)| {
+            if card == CardKind::Curse {
+                *count = 0;
+            }
+        });
+
+        assert_eq!(
+            curse(&mut arena, ignored_player_id, CardKind::Witch),
+            Outcome::None
+        );
+        assert_eq!(
+            arena.player(other_player_id).unwrap().discard_pile,
+            cardvec![]
+        );
+    }
+
+    #[test]
+    fn curses_gained_in_turn_order() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 3, 1);
+        let ignored_player_id = 1;
+
+        arena.current_player_id = 1;
+        arena.supply.iter_mut().for_each(|(card, count)| {
+            if card == CardKind::Curse {
+                *count = 1;
+            }
+        });
+
+        assert_eq!(
+            curse(&mut arena, ignored_player_id, CardKind::Witch),
+            Outcome::None
+        );
+        assert_eq!(
+            arena.player(2).unwrap().discard_pile,
+            cardvec![CardKind::Curse]
+        );
+        assert_eq!(arena.player(0).unwrap().discard_pile, cardvec![]);
+    }
+}
@@ -1,6 +1,17 @@
 use super::prelude::*;
 
-pub(super) const EFFECT: &Effect = &Effect::Conditional(func, "Gain a card costing up to $4.");
+pub(super) const EFFECT: &Effect = &Effect::Conditional(
+    func,
+    &Decision {
+        description: "Gain a card costing up to $4.",
+        chooser: Chooser::CurrentPlayer,
+        zone: Zone::Supply,
+        count: CardCount::Exactly(1),
+        filter: CardFilter::Any,
+        max_cost: Some(CostBound::UpTo(4)),
+        optional: false,
+    },
+);
 
 fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outcome> {
     let error = Err(Error::UnresolvedActionEffect(&EFFECT.description()));
@@ -24,7 +35,10 @@ fn func(arena: &mut Arena, player_id: usize, cards: &[CardKind]) -> Result<Outco
         arena
             .supply
             .move_card(&mut current_player!(arena).discard_pile, cards[0])
-            .and(Ok(Outcome::None))
+            .map(|card| {
+                arena.log.emit(GameEvent::Gain { player_id, card });
+                Outcome::None
+            })
             .or(error)
     } else {
         error
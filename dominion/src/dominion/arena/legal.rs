@@ -0,0 +1,373 @@
+use crate::dominion::decision::{CardCount, Chooser, CostBound, Decision, Zone};
+use crate::dominion::turn::Turn;
+use crate::dominion::types::{Error, Move, Result};
+use crate::dominion::{Arena, CardKind};
+
+impl Arena {
+    /// Returns every move that `player_id` can currently make. While a
+    /// decision is pending, only selections that resolve it are returned.
+    /// Selections are returned once for each combination of cards, except
+    /// when the order of the cards matters.
+    ///
+    /// # Errors
+    ///
+    /// If `player_id` does not exist, then an error is returned.
+    pub fn legal_moves(&self, player_id: usize) -> Result<Vec<Move>> {
+        self.player(player_id)?;
+
+        if let Some(decision) = self.pending_decision() {
+            return Ok(self
+                .legal_selections(player_id, decision)
+                .into_iter()
+                .map(Move::Select)
+                .collect());
+        }
+
+        let mut moves = Vec::new();
+
+        if (player_id != self.current_player_id) || self.is_game_over() {
+            return Ok(moves);
+        }
+
+        let hand = distinct(&self.current_player().hand);
+
+        match self.turn {
+            Turn::Action(action_phase) => {
+                if action_phase.remaining_actions > 0 {
+                    moves.extend(
+                        hand.iter()
+                            .filter(|&&(card, _)| card.is_action())
+                            .map(|&(card, _)| Move::Play(card)),
+                    );
+                }
+            }
+            Turn::Buy(buy_phase) => {
+                moves.extend(
+                    hand.iter()
+                        .filter(|&&(card, _)| card.is_treasure())
+                        .map(|&(card, _)| Move::Play(card)),
+                );
+
+                if buy_phase.remaining_buys > 0 {
+                    moves.extend(
+                        self.supply
+                            .iter()
+                            .filter(|&(card, &count)| {
+                                (count > 0) && (card.cost() <= buy_phase.remaining_copper)
+                            })
+                            .map(|(card, _)| Move::Buy(card)),
+                    );
+                }
+            }
+        }
+
+        moves.push(Move::EndPhase);
+
+        Ok(moves)
+    }
+
+    /// Makes `mv` for `player_id`. Only the current player can play or buy
+    /// cards or end a phase.
+    pub fn make_move(&mut self, player_id: usize, mv: &Move) -> Result<()> {
+        match mv {
+            Move::Select(cards) => self.select_cards(player_id, cards),
+            _ if player_id != self.current_player_id => Err(Error::InvalidPlayerId),
+            &Move::Play(card) => self.play_card(card),
+            &Move::Buy(card) => self.buy_card(card),
+            Move::EndPhase => self.end_turn_phase(),
+            Move::Undo => self.undo(),
+        }
+    }
+
+    fn must_choose(&self, player_id: usize, decision: &Decision) -> bool {
+        match decision.chooser {
+            Chooser::CurrentPlayer => player_id == self.current_player_id,
+            Chooser::OtherPlayers => {
+                let player = &self.players[player_id];
+
+                if player_id == self.current_player_id {
+                    false
+                } else if self.players.iter().any(|player| player.reacting) {
+                    // Only players that can respond to an Attack choose while
+                    // the Reaction window is open.
+                    player.reacting
+                } else if let Zone::Stage = decision.zone {
+                    !player.stage.is_empty()
+                } else {
+                    // Each other player chooses once for the attack.
+                    !player.responded
+                }
+            }
+        }
+    }
+
+    fn legal_selections(&self, player_id: usize, decision: &Decision) -> Vec<Vec<CardKind>> {
+        if !self.must_choose(player_id, decision) {
+            return Vec::new();
+        }
+
+        let player = &self.players[player_id];
+
+        if player.immune && (decision.chooser == Chooser::OtherPlayers) {
+            // Player revealed a Reaction and is unaffected.
+            return vec![Vec::new()];
+        }
+
+        let zone: Vec<CardKind> = match decision.zone {
+            Zone::Hand => player.hand.to_vec(),
+            Zone::DrawnCard => player.hand.last().copied().into_iter().collect(),
+            Zone::DiscardPile => player.discard_pile.to_vec(),
+            Zone::TopOfDiscardPile => player.discard_pile.last().copied().into_iter().collect(),
+            Zone::Stage => player.stage.to_vec(),
+            Zone::Supply => self
+                .supply
+                .iter()
+                .filter(|&(_, &count)| count > 0)
+                .map(|(card, _)| card)
+                .collect(),
+        };
+
+        let max_cost = decision.max_cost.map(|bound| match bound {
+            CostBound::UpTo(cost) => cost,
+            // The trashed card is always on top of the trash.
+            CostBound::UpToTrashedPlus(cost) => {
+                self.trash.last().map_or(0, |card| card.cost()) + cost
+            }
+        });
+
+        let candidates: Vec<CardKind> = zone
+            .iter()
+            .copied()
+            .filter(|&card| decision.filter.allows(card))
+            .filter(|&card| max_cost.map_or(true, |cost| card.cost() <= cost))
+            .collect();
+        let n = candidates.len();
+
+        let (min, max) = match decision.count {
+            CardCount::Exactly(count) => (count.min(n), count.min(n)),
+            CardCount::UpTo(count) => (0, count.min(n)),
+            CardCount::Any => (0, n),
+            CardCount::DownTo(count) => {
+                let count = zone.len().saturating_sub(count);
+                (count, count)
+            }
+            CardCount::PerEmptySupplyPile => {
+                let empty = self.supply.iter().filter(|&(_, &count)| count == 0).count();
+                (empty.min(n), empty.min(n))
+            }
+            CardCount::All => return permutations(&distinct(&candidates)),
+        };
+        let min = if decision.optional { 0 } else { min };
+
+        let candidates = distinct(&candidates);
+        (min..=max)
+            .flat_map(|size| combinations(&candidates, size))
+            .collect()
+    }
+}
+
+/// Returns each distinct card in `cards` with the number of copies.
+fn distinct(cards: &[CardKind]) -> Vec<(CardKind, usize)> {
+    let mut counts: Vec<(CardKind, usize)> = Vec::new();
+
+    for &card in cards {
+        match counts.iter_mut().find(|(other, _)| *other == card) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((card, 1)),
+        }
+    }
+
+    counts
+}
+
+/// Returns every distinct combination of `size` cards.
+fn combinations(cards: &[(CardKind, usize)], size: usize) -> Vec<Vec<CardKind>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+
+    let (&(card, count), rest) = match cards.split_first() {
+        Some(x) => x,
+        None => return Vec::new(),
+    };
+
+    (0..=count.min(size))
+        .flat_map(|copies| {
+            combinations(rest, size - copies)
+                .into_iter()
+                .map(move |mut combination| {
+                    combination.splice(0..0, std::iter::repeat(card).take(copies));
+                    combination
+                })
+        })
+        .collect()
+}
+
+/// Returns every distinct ordering of all cards.
+fn permutations(cards: &[(CardKind, usize)]) -> Vec<Vec<CardKind>> {
+    if cards.iter().all(|&(_, count)| count == 0) {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+
+    for i in 0..cards.len() {
+        if cards[i].1 == 0 {
+            continue;
+        }
+
+        let mut rest = cards.to_vec();
+        rest[i].1 -= 1;
+
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, cards[i].0);
+            result.push(permutation);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::turn::BuyPhase;
+    use crate::dominion::KingdomSet;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn combinations_of_repeated_cards() {
+        let cards = distinct(&[CardKind::Copper, CardKind::Estate, CardKind::Copper]);
+
+        assert_eq!(combinations(&cards, 0), vec![vec![]]);
+        assert_eq!(
+            combinations(&cards, 2),
+            vec![
+                vec![CardKind::Copper, CardKind::Estate],
+                vec![CardKind::Copper, CardKind::Copper],
+            ]
+        );
+        assert_eq!(combinations(&cards, 4), Vec::<Vec<CardKind>>::new());
+    }
+
+    #[test]
+    fn permutations_of_repeated_cards() {
+        let cards = distinct(&[CardKind::Gold, CardKind::Gold, CardKind::Silver]);
+
+        assert_eq!(permutations(&cards).len(), 3);
+    }
+
+    #[test]
+    fn action_phase_moves() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        arena.players[0].hand.clear();
+        arena.players[0].hand.push(CardKind::Village);
+        arena.players[0].hand.push(CardKind::Village);
+        arena.players[0].hand.push(CardKind::Copper);
+
+        assert_eq!(
+            arena.legal_moves(0),
+            Ok(vec![Move::Play(CardKind::Village), Move::EndPhase])
+        );
+        assert_eq!(arena.legal_moves(1), Ok(vec![]));
+        assert_eq!(arena.legal_moves(2), Err(Error::InvalidPlayerId));
+    }
+
+    #[test]
+    fn buy_phase_moves() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        arena.players[0].hand.clear();
+        arena.players[0].hand.push(CardKind::Silver);
+        arena.turn = Turn::Buy(BuyPhase {
+            remaining_buys: 1,
+            remaining_copper: 2,
+        });
+
+        let moves = arena.legal_moves(0).unwrap();
+
+        assert!(moves.contains(&Move::Play(CardKind::Silver)));
+        assert!(moves.contains(&Move::Buy(CardKind::Moat)));
+        assert!(moves.contains(&Move::Buy(CardKind::Copper)));
+        assert!(!moves.contains(&Move::Buy(CardKind::Silver)));
+        assert_eq!(moves.last(), Some(&Move::EndPhase));
+
+        arena.turn.as_buy_phase_mut().unwrap().remaining_buys = 0;
+
+        assert_eq!(
+            arena.legal_moves(0),
+            Ok(vec![Move::Play(CardKind::Silver), Move::EndPhase])
+        );
+    }
+
+    #[test]
+    fn pending_decision_moves() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        arena.players[0].hand.clear();
+        arena.players[0].hand.push(CardKind::Remodel);
+        arena.players[0].hand.push(CardKind::Estate);
+        arena.players[0].hand.push(CardKind::Estate);
+
+        assert!(arena.play_card(CardKind::Remodel).is_err());
+
+        assert_eq!(
+            arena.legal_moves(0),
+            Ok(vec![Move::Select(vec![CardKind::Estate])])
+        );
+        assert_eq!(arena.legal_moves(1), Ok(vec![]));
+    }
+
+    #[test]
+    fn random_playout_only_makes_legal_moves() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for seed in 0..10 {
+            let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, seed);
+
+            for _ in 0..2000 {
+                if arena.is_game_over() {
+                    break;
+                }
+
+                let (player_id, moves) = (0..arena.players.len())
+                    .map(|id| (id, arena.legal_moves(id).unwrap()))
+                    .find(|(_, moves)| !moves.is_empty())
+                    .unwrap();
+                let mv = &moves[rng.gen_range(0, moves.len())];
+                let before = format!("{:?}", arena);
+
+                // A selection that is rejected leaves the game unchanged,
+                // although another player's selection clears the undo history.
+                if arena.pending_decision().is_some() {
+                    let saved = |arena: &Arena| {
+                        let mut buffer = Vec::new();
+                        arena.save(&mut buffer).unwrap();
+                        buffer
+                    };
+                    let saved_before = saved(&arena);
+
+                    let illegal = Move::Select(vec![CardKind::Province; 6]);
+                    assert!(arena.make_move(player_id, &illegal).is_err());
+                    assert_eq!(saved(&arena), saved_before);
+                }
+
+                match (mv, arena.make_move(player_id, mv)) {
+                    (_, Ok(())) => (),
+                    // Playing an Action card can leave a decision pending.
+                    (Move::Play(_), Err(Error::UnresolvedActionEffect(_))) => {
+                        assert!(arena.pending_decision().is_some())
+                    }
+                    // A selection can move the game on to a new decision.
+                    (Move::Select(_), Err(Error::UnresolvedActionEffect(_))) => assert_ne!(
+                        format!("{:?}", arena),
+                        before,
+                        "player {} failed to make move {:?}",
+                        player_id,
+                        mv
+                    ),
+                    (_, r) => panic!("player {} failed to make move {:?}: {:?}", player_id, mv, r),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::dominion::event::GameEvent;
+use crate::dominion::CardKind;
+use serde::{Deserialize, Serialize};
+
+// Subscribers are `Send` so that an arena can be moved to another thread.
+type Subscriber = Box<dyn FnMut(&GameEvent) + Send>;
+
+/// Record of every event in a game. A subscriber, if set, is called with each
+/// event as it is recorded.
+#[derive(Default, Deserialize, Serialize)]
+pub(super) struct EventLog {
+    pub(super) events: Vec<GameEvent>,
+    #[serde(skip)]
+    pub(super) subscriber: Option<Subscriber>,
+}
+
+// Subscribers can't be cloned so a cloned log has no subscriber.
+impl Clone for EventLog {
+    fn clone(&self) -> Self {
+        Self {
+            events: self.events.clone(),
+            subscriber: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for EventLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "EventLog({} events)", self.events.len())
+    }
+}
+
+impl EventLog {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn emit(&mut self, event: GameEvent) {
+        if let Some(subscriber) = self.subscriber.as_mut() {
+            subscriber(&event);
+        }
+
+        self.events.push(event);
+    }
+
+    /// Emits an event for each card in `cards`.
+    pub(super) fn emit_all(&mut self, cards: &[CardKind], event: impl Fn(CardKind) -> GameEvent) {
+        for &card in cards {
+            self.emit(event(card));
+        }
+    }
+}
@@ -1,92 +1,148 @@
+use super::log::EventLog;
+use super::victory;
+use crate::dominion::event::GameEvent;
 use crate::dominion::location::CardVec;
 use crate::dominion::CardKind;
-use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::SeedableRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
 
-static mut RNG: Option<StdRng> = None;
-
-unsafe fn rng() -> &'static mut StdRng {
-    if RNG.is_none() {
-        RNG = Some(StdRng::seed_from_u64(1));
-    }
-
-    RNG.as_mut().unwrap()
-}
-
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub(super) struct Player {
+    pub(super) id: usize,
     pub(super) draw_pile: CardVec,
     pub(super) hand: CardVec,
     pub(super) play_zone: CardVec,
     pub(super) stage: CardVec,
     pub(super) discard_pile: CardVec,
+    // Set while the player may still reveal a Reaction to the attack being played.
+    pub(super) reacting: bool,
+    // Set if the player revealed a Reaction and is unaffected by the attack being played.
+    pub(super) immune: bool,
+    // Set once the player has made their selection for the attack being played.
+    pub(super) responded: bool,
+    // Number of turns started by the player.
+    pub(super) turns: u32,
 }
 
 impl Player {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(id: usize, rng: &mut impl RngCore) -> Self {
         let mut draw_pile = vec![CardKind::Copper; 7];
         draw_pile.append(&mut vec![CardKind::Estate; 3]);
 
         let mut player = Self {
+            id,
             draw_pile: CardVec(draw_pile),
             hand: CardVec::new(),
             play_zone: CardVec::new(),
             stage: CardVec::new(),
             discard_pile: CardVec::new(),
+            reacting: false,
+            immune: false,
+            responded: false,
+            turns: 0,
         };
 
-        player.shuffle_deck();
+        player.shuffle_deck(rng);
 
         player
     }
 
-    pub(super) fn draw_card(&mut self) -> Option<CardKind> {
-        if self.draw_pile.is_empty() {
-            std::mem::swap(&mut self.draw_pile, &mut self.discard_pile);
-            self.shuffle_deck();
-        }
+    pub(super) fn draw_card(
+        &mut self,
+        rng: &mut impl RngCore,
+        log: &mut EventLog,
+    ) -> Option<CardKind> {
+        let player_id = self.id;
 
-        // We consider the top of the draw pile to be the end that is popped.
-        self.draw_pile.pop().map(|card| {
+        self.take_top_card(rng, log).map(|card| {
             self.hand.push(card);
+            log.emit(GameEvent::Draw { player_id, card });
             card
         })
     }
 
-    pub(super) fn cleanup(&mut self) {
+    /// Moves the top card of the draw pile to the stage so that it can be
+    /// looked at or revealed.
+    pub(super) fn stage_card(
+        &mut self,
+        rng: &mut impl RngCore,
+        log: &mut EventLog,
+    ) -> Option<CardKind> {
+        self.take_top_card(rng, log).map(|card| {
+            self.stage.push(card);
+            card
+        })
+    }
+
+    fn take_top_card(&mut self, rng: &mut impl RngCore, log: &mut EventLog) -> Option<CardKind> {
+        if self.draw_pile.is_empty() && !self.discard_pile.is_empty() {
+            std::mem::swap(&mut self.draw_pile, &mut self.discard_pile);
+            self.shuffle_deck(rng);
+            log.emit(GameEvent::Shuffle { player_id: self.id });
+        }
+
+        // We consider the top of the draw pile to be the end that is popped.
+        self.draw_pile.pop()
+    }
+
+    pub(super) fn cleanup(&mut self, rng: &mut impl RngCore, log: &mut EventLog) {
+        let player_id = self.id;
+
+        log.emit_all(&self.play_zone, |card| GameEvent::Discard {
+            player_id,
+            card,
+        });
+        log.emit_all(&self.hand, |card| GameEvent::Discard { player_id, card });
         self.discard_pile.append(&mut self.play_zone);
         self.discard_pile.append(&mut self.hand);
 
         for _ in 0..5 {
-            self.draw_card();
+            self.draw_card(rng, log);
         }
     }
 
-    pub(super) fn in_deck(&self, card: CardKind) -> bool {
+    /// Returns an iterator over every card the player owns.
+    pub(super) fn deck(&self) -> impl Iterator<Item = &CardKind> {
         self.draw_pile
             .iter()
             .chain(self.hand.iter())
             .chain(self.play_zone.iter())
             .chain(self.stage.iter())
             .chain(self.discard_pile.iter())
-            .any(|&x| x == card)
     }
 
-    fn shuffle_deck(&mut self) {
-        unsafe {
-            self.draw_pile.shuffle(rng());
-        }
+    pub(super) fn in_deck(&self, card: CardKind) -> bool {
+        self.deck().any(|&x| x == card)
+    }
+
+    pub(super) fn victory_points(&self) -> i32 {
+        let deck: Vec<_> = self.deck().copied().collect();
+
+        deck.iter()
+            .map(|&card| victory::victory_points(card, &deck))
+            .sum()
+    }
+
+    fn shuffle_deck(&mut self, rng: &mut impl RngCore) {
+        self.draw_pile.shuffle(rng);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(1)
+    }
 
     #[test]
     fn test_draw_card_no_shuffle() {
-        let mut p = Player::new();
+        let mut rng = rng();
+        let mut p = Player::new(0, &mut rng);
 
         p.draw_pile.clear();
         assert!(p.draw_pile.is_empty());
@@ -94,7 +150,7 @@ mod tests {
         p.draw_pile.push(CardKind::Silver);
         p.draw_pile.push(CardKind::Copper);
 
-        p.draw_card();
+        p.draw_card(&mut rng, &mut EventLog::new());
 
         assert_eq!(p.draw_pile, cardvec![CardKind::Silver]);
         assert_eq!(p.hand, cardvec![CardKind::Copper]);
@@ -102,7 +158,8 @@ mod tests {
 
     #[test]
     fn test_draw_card_shuffle() {
-        let mut p = Player::new();
+        let mut rng = rng();
+        let mut p = Player::new(0, &mut rng);
 
         p.draw_pile.clear();
         assert!(p.draw_pile.is_empty());
@@ -111,14 +168,44 @@ mod tests {
             p.discard_pile.push(CardKind::Copper);
         }
 
-        p.draw_card();
+        let mut log = EventLog::new();
+
+        p.draw_card(&mut rng, &mut log);
         assert_eq!(p.draw_pile, cardvec![CardKind::Copper; 4]);
         assert_eq!(p.hand, cardvec![CardKind::Copper]);
+        assert_eq!(
+            log.events,
+            vec![
+                GameEvent::Shuffle { player_id: 0 },
+                GameEvent::Draw {
+                    player_id: 0,
+                    card: CardKind::Copper
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stage_card_shuffle() {
+        let mut rng = rng();
+        let mut p = Player::new(0, &mut rng);
+
+        p.draw_pile.clear();
+        p.discard_pile.push(CardKind::Silver);
+
+        assert_eq!(
+            p.stage_card(&mut rng, &mut EventLog::new()),
+            Some(CardKind::Silver)
+        );
+        assert_eq!(p.stage, cardvec![CardKind::Silver]);
+        assert!(p.discard_pile.is_empty());
+        assert_eq!(p.stage_card(&mut rng, &mut EventLog::new()), None);
     }
 
     #[test]
     fn test_draw_card_empty_draw_and_discard() {
-        let mut p = Player::new();
+        let mut rng = rng();
+        let mut p = Player::new(0, &mut rng);
 
         p.draw_pile.clear();
         p.discard_pile.clear();
@@ -126,7 +213,7 @@ mod tests {
         p.hand.push(CardKind::Copper);
         p.hand.push(CardKind::Copper);
 
-        p.draw_card();
+        p.draw_card(&mut rng, &mut EventLog::new());
         assert_eq!(p.draw_pile, cardvec![]);
         assert_eq!(p.discard_pile, cardvec![]);
         assert_eq!(p.hand, cardvec![CardKind::Copper; 2]);
@@ -134,7 +221,8 @@ mod tests {
 
     #[test]
     fn test_cleanup() {
-        let mut p = Player::new();
+        let mut rng = rng();
+        let mut p = Player::new(0, &mut rng);
 
         p.draw_pile.clear();
         assert!(p.draw_pile.is_empty());
@@ -145,7 +233,7 @@ mod tests {
         p.play_zone.push(CardKind::Silver);
         p.hand.push(CardKind::Gold);
 
-        p.cleanup();
+        p.cleanup(&mut rng, &mut EventLog::new());
 
         assert!(p.discard_pile.iter().any(|&x| x == CardKind::Silver));
         assert!(p.discard_pile.iter().any(|&x| x == CardKind::Gold));
@@ -154,9 +242,48 @@ mod tests {
         assert!(p.draw_pile.is_empty());
     }
 
+    #[test]
+    fn test_same_seed_same_shuffle() {
+        let p1 = Player::new(0, &mut rng());
+        let p2 = Player::new(0, &mut rng());
+
+        assert_eq!(p1.draw_pile, p2.draw_pile);
+    }
+
+    #[test]
+    fn test_victory_points() {
+        let mut p = Player::new(0, &mut rng());
+
+        assert_eq!(p.victory_points(), 3);
+
+        p.hand.push(CardKind::Province);
+        p.discard_pile.push(CardKind::Curse);
+        p.play_zone.push(CardKind::Duchy);
+
+        assert_eq!(p.victory_points(), 11);
+    }
+
+    #[test]
+    fn test_victory_points_gardens() {
+        let mut p = Player::new(0, &mut rng());
+
+        p.hand.push(CardKind::Gardens);
+        p.hand.push(CardKind::Gardens);
+
+        // 3 Estates and 2 Gardens in a 12 card deck.
+        assert_eq!(p.victory_points(), 5);
+
+        for _ in 0..8 {
+            p.discard_pile.push(CardKind::Copper);
+        }
+
+        // 3 Estates and 2 Gardens in a 20 card deck.
+        assert_eq!(p.victory_points(), 7);
+    }
+
     #[test]
     fn test_card_in_deck() {
-        let p = Player::new();
+        let p = Player::new(0, &mut rng());
 
         assert!(p.in_deck(CardKind::Copper));
         assert!(!p.in_deck(CardKind::Gold));
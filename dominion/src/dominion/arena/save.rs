@@ -0,0 +1,208 @@
+use crate::dominion::config::{MAX_PLAYERS, MIN_PLAYERS};
+use crate::dominion::Arena;
+use serde::de::Error as _;
+use std::io::{Read, Write};
+
+/// An item that is referenced with a `&'static` pointer and saved by name.
+pub(super) trait Named: PartialEq + 'static {
+    const REGISTRY: &'static [(&'static str, &'static Self)];
+
+    fn name(&self) -> Option<&'static str> {
+        Self::REGISTRY
+            .iter()
+            .find(|(_, item)| *item == self)
+            .map(|(name, _)| *name)
+    }
+
+    fn from_name(name: &str) -> Option<&'static Self> {
+        Self::REGISTRY
+            .iter()
+            .find(|(item_name, _)| *item_name == name)
+            .map(|(_, item)| *item)
+    }
+}
+
+/// Serializes a collection of `&'static` items by name. Used with
+/// `#[serde(with = "save::static_refs")]`.
+pub(super) mod static_refs {
+    use super::Named;
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::iter::FromIterator;
+
+    pub(in crate::dominion::arena) fn serialize<'a, T, C, S>(
+        items: &'a C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Named,
+        &'a C: IntoIterator<Item = &'a &'static T>,
+        S: Serializer,
+    {
+        let names = items
+            .into_iter()
+            .map(|item| item.name())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| S::Error::custom("unregistered item"))?;
+
+        serializer.collect_seq(names)
+    }
+
+    pub(in crate::dominion::arena) fn deserialize<'de, T, C, D>(
+        deserializer: D,
+    ) -> Result<C, D::Error>
+    where
+        T: Named,
+        C: FromIterator<&'static T>,
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|name| {
+                T::from_name(name).ok_or_else(|| D::Error::custom(format!("unknown item {}", name)))
+            })
+            .collect()
+    }
+}
+
+impl Arena {
+    /// Writes the entire game state as JSON, including any pending decision
+    /// and the state of the random number generator. Event subscribers are
+    /// not saved.
+    #[allow(dead_code)]
+    pub fn save<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Reads a game previously written with `save`.
+    ///
+    /// # Errors
+    ///
+    /// If the game can't be read or is not consistent, then an error is
+    /// returned.
+    #[allow(dead_code)]
+    pub fn load<R: Read>(reader: R) -> serde_json::Result<Self> {
+        let arena: Self = serde_json::from_reader(reader)?;
+        arena.validate()?;
+
+        Ok(arena)
+    }
+
+    /// Checks that a game that was read is consistent, so that a modified or
+    /// corrupted save is rejected instead of causing a panic later.
+    pub(crate) fn validate(&self) -> serde_json::Result<()> {
+        let num_players = self.players.len();
+
+        if (num_players < MIN_PLAYERS) || (num_players > MAX_PLAYERS) {
+            return Err(serde_json::Error::custom("invalid number of players"));
+        }
+        if self.current_player_id >= num_players {
+            return Err(serde_json::Error::custom("invalid current player"));
+        }
+        if self
+            .players
+            .iter()
+            .enumerate()
+            .any(|(id, player)| player.id != id)
+        {
+            return Err(serde_json::Error::custom("players are out of order"));
+        }
+
+        self.supply
+            .validate(num_players)
+            .map_err(serde_json::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dominion::{Arena, CardKind, KingdomSet};
+
+    fn setup_arena() -> Arena {
+        Arena::with_seed(KingdomSet::FirstGame, 2, 1)
+    }
+
+    fn reload(arena: &Arena) -> Arena {
+        let mut buffer = Vec::new();
+        arena.save(&mut buffer).unwrap();
+
+        Arena::load(buffer.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn save_and_load_new_game() {
+        let arena = setup_arena();
+
+        assert_eq!(format!("{:?}", reload(&arena)), format!("{:?}", arena));
+        assert_eq!(reload(&arena).events(), arena.events());
+    }
+
+    #[test]
+    fn save_and_load_pending_decision() {
+        let mut arena = setup_arena();
+        let player_id = arena.current_player_id;
+
+        arena.current_player_mut().hand.push(CardKind::Workshop);
+        arena.play_card(CardKind::Workshop).unwrap_err();
+
+        let mut loaded = reload(&arena);
+        assert_eq!(loaded.pending_decision(), arena.pending_decision());
+
+        loaded.select_cards(player_id, &[CardKind::Silver]).unwrap();
+        assert_eq!(loaded.pending_decision(), None);
+        assert_eq!(
+            loaded.current_player().discard_pile.last(),
+            Some(&CardKind::Silver)
+        );
+    }
+
+    #[test]
+    fn save_and_load_trigger() {
+        let mut arena = setup_arena();
+
+        arena.current_player_mut().hand.push(CardKind::Merchant);
+        arena.play_card(CardKind::Merchant).unwrap();
+
+        assert_eq!(reload(&arena).triggers, arena.triggers);
+    }
+
+    #[test]
+    fn load_rejects_invalid_current_player() {
+        let arena = setup_arena();
+        let mut buffer = Vec::new();
+        arena.save(&mut buffer).unwrap();
+
+        let json = String::from_utf8(buffer)
+            .unwrap()
+            .replace("\"current_player_id\":0", "\"current_player_id\":2");
+
+        assert!(Arena::load(json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn load_rejects_card_without_pile() {
+        let arena = setup_arena();
+        let mut buffer = Vec::new();
+        arena.save(&mut buffer).unwrap();
+
+        let json = String::from_utf8(buffer)
+            .unwrap()
+            .replacen("\"Copper\":", "\"Witch\":", 1);
+
+        assert!(Arena::load(json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn loaded_game_continues_with_same_shuffles() {
+        let mut arena = setup_arena();
+        let mut loaded = reload(&arena);
+
+        for _ in 0..6 {
+            arena.end_turn_phase().unwrap();
+            loaded.end_turn_phase().unwrap();
+        }
+
+        assert_eq!(loaded.events(), arena.events());
+    }
+}
@@ -1,19 +1,43 @@
+use crate::dominion::config::{PileConfig, MAX_PLAYERS, MIN_PLAYERS};
 use crate::dominion::location::Location;
 use crate::dominion::types::{Error, Result};
 use crate::dominion::CardKind;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fmt;
 
+// Games with 5 or 6 players use the Treasures of two base sets, and 3
+// Provinces per player.
 const BASE_CARDS: &[(CardKind, &'static dyn Fn(usize) -> usize)] = &[
-    (CardKind::Copper, &|n| 60 - 7 * n),
-    (CardKind::Silver, &|_| 40),
-    (CardKind::Gold, &|_| 30),
+    (CardKind::Copper, &|n| {
+        if n > 4 {
+            120 - 7 * n
+        } else {
+            60 - 7 * n
+        }
+    }),
+    (CardKind::Silver, &|n| if n > 4 { 80 } else { 40 }),
+    (CardKind::Gold, &|n| if n > 4 { 60 } else { 30 }),
     (CardKind::Estate, &|n| if n > 2 { 12 } else { 8 }),
     (CardKind::Duchy, &|n| if n > 2 { 12 } else { 8 }),
-    (CardKind::Province, &|n| if n > 2 { 12 } else { 8 }),
+    (CardKind::Province, &|n| match n {
+        2 => 8,
+        3 | 4 => 12,
+        _ => 3 * n,
+    }),
     (CardKind::Curse, &|n| 10 * (n - 1)),
 ];
 
+/// Returns the number of empty supply piles that end the game.
+fn empty_piles_to_end(num_players: usize) -> usize {
+    if num_players > 4 {
+        4
+    } else {
+        3
+    }
+}
+
 fn kingdom_card_size(card_id: CardKind, num_players: usize) -> usize {
     if card_id.is_victory() {
         if num_players > 2 {
@@ -26,94 +50,164 @@ fn kingdom_card_size(card_id: CardKind, num_players: usize) -> usize {
     }
 }
 
-type Entry = (CardKind, usize);
-type EntryRef<'a> = (CardKind, &'a usize);
-type EntryMut<'a> = (CardKind, &'a mut usize);
+/// Returns a card whose pile can't be set up with `kingdom` and `piles`, if
+/// any. Extra piles can't repeat another pile, and each ending pile must be
+/// in the supply once.
+pub(crate) fn invalid_pile(kingdom: &[CardKind], piles: &PileConfig) -> Option<CardKind> {
+    let is_base = |card| BASE_CARDS.iter().any(|&(base, _)| base == card);
+
+    let invalid_extra = piles.extra.iter().enumerate().find(|&(i, &(card, _))| {
+        is_base(card) || kingdom.contains(&card) || piles.extra[..i].iter().any(|&(k, _)| k == card)
+    });
+    if let Some((_, &(card, _))) = invalid_extra {
+        return Some(card);
+    }
+
+    piles
+        .ending
+        .iter()
+        .enumerate()
+        .find(|&(i, &card)| {
+            let in_supply = is_base(card)
+                || kingdom.contains(&card)
+                || piles.extra.iter().any(|&(k, _)| k == card);
+
+            !in_supply || piles.ending[..i].contains(&card)
+        })
+        .map(|(_, &card)| card)
+}
 
-#[derive(Debug)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Supply {
-    pub kingdom_cards: Vec<Entry>,
-    pub base_cards: Vec<Entry>,
+    /// Number of cards left in the pile of each card.
+    piles: HashMap<CardKind, usize>,
+    /// Every card in the supply in the order they are shown, starting with
+    /// the kingdom cards.
+    cards: Vec<CardKind>,
+    num_kingdom_cards: usize,
+    /// Cards whose pile ends the game when empty.
+    ending_piles: Vec<CardKind>,
+    num_players: usize,
 }
 
 impl Supply {
-    pub fn new(kingdom_card_ids: &'static [CardKind], num_players: usize) -> Self {
-        Self {
-            kingdom_cards: kingdom_card_ids
-                .iter()
-                .map(|&card_id| (card_id, kingdom_card_size(card_id, num_players)))
-                .collect(),
-            base_cards: BASE_CARDS
-                .iter()
-                .map(|&(id, f)| (id, f(num_players)))
-                .collect(),
+    /// Returns a full supply for a game with `num_players` players, the
+    /// kingdom cards, the base cards and the extra piles in `piles`.
+    ///
+    /// # Errors
+    ///
+    /// If `num_players` is not from 2 to 6, then `Error::InvalidPlayerCount` is
+    /// returned. If `kingdom_card_ids` contains a card that is not a kingdom
+    /// card or contains a card twice, or if `piles` is not valid for the
+    /// kingdom, then `Error::InvalidCard` is returned.
+    pub fn new(
+        kingdom_card_ids: &[CardKind],
+        num_players: usize,
+        piles: &PileConfig,
+    ) -> Result<Self> {
+        if (num_players < MIN_PLAYERS) || (num_players > MAX_PLAYERS) {
+            return Err(Error::InvalidPlayerCount);
         }
-    }
 
-    pub fn get_entry(&self, index: usize) -> Option<EntryRef> {
-        let n = self.kingdom_cards.len();
+        for (i, card) in kingdom_card_ids.iter().enumerate() {
+            if !CardKind::KINGDOM.contains(card) || kingdom_card_ids[..i].contains(card) {
+                return Err(Error::InvalidCard);
+            }
+        }
 
-        let entry = if index < n {
-            Some(&self.kingdom_cards[index])
-        } else if (index - n) < self.base_cards.len() {
-            Some(&self.base_cards[index - n])
-        } else {
-            None
-        };
+        if invalid_pile(kingdom_card_ids, piles).is_some() {
+            return Err(Error::InvalidCard);
+        }
 
-        entry.map(|&(k, ref v)| (k, v))
+        let entries: Vec<_> = kingdom_card_ids
+            .iter()
+            .map(|&card_id| (card_id, kingdom_card_size(card_id, num_players)))
+            .chain(BASE_CARDS.iter().map(|&(id, f)| (id, f(num_players))))
+            .chain(piles.extra.iter().cloned())
+            .collect();
+
+        Ok(Self {
+            piles: entries.iter().cloned().collect(),
+            cards: entries.iter().map(|&(card, _)| card).collect(),
+            num_kingdom_cards: kingdom_card_ids.len(),
+            ending_piles: piles.ending.clone(),
+            num_players,
+        })
     }
 
-    pub fn get_entry_mut(&mut self, index: usize) -> Option<EntryMut> {
-        let n = self.kingdom_cards.len();
-
-        let entry = if index < n {
-            Some(&mut self.kingdom_cards[index])
-        } else if (index - n) < self.base_cards.len() {
-            Some(&mut self.base_cards[index - n])
+    /// Checks that a supply that was read has exactly one pile for each card
+    /// and is set up for `num_players` players.
+    pub(super) fn validate(&self, num_players: usize) -> std::result::Result<(), &'static str> {
+        let has_duplicates = self
+            .cards
+            .iter()
+            .enumerate()
+            .any(|(i, card)| self.cards[..i].contains(card));
+
+        if has_duplicates
+            || (self.cards.len() != self.piles.len())
+            || !self.cards.iter().all(|card| self.piles.contains_key(card))
+        {
+            Err("supply cards do not match piles")
+        } else if (self.num_kingdom_cards > self.cards.len())
+            || !self
+                .ending_piles
+                .iter()
+                .all(|card| self.piles.contains_key(card))
+        {
+            Err("invalid supply piles")
+        } else if self.num_players != num_players {
+            Err("supply is for a different number of players")
         } else {
-            None
-        };
+            Ok(())
+        }
+    }
+
+    /// Returns the kingdom cards in the order they were given.
+    pub fn kingdom(&self) -> &[CardKind] {
+        &self.cards[..self.num_kingdom_cards]
+    }
 
-        entry.map(|&mut (k, ref mut v)| (k, v))
+    /// Returns the number of cards left in the pile for `card`, or `None` if
+    /// `card` is not in the supply.
+    pub fn count(&self, card: CardKind) -> Option<usize> {
+        self.piles.get(&card).copied()
     }
 
+    /// Returns true if any of the ending piles is empty, or if enough piles
+    /// are empty for the number of players.
     pub fn is_game_over(&self) -> bool {
-        const PROVINCE_INDEX: usize = 5;
+        let ending_pile_empty = self
+            .ending_piles
+            .iter()
+            .any(|&card| self.count(card) == Some(0));
 
-        if self.base_cards[PROVINCE_INDEX].1 == 0 {
-            true
-        } else {
-            self.iter()
-                .filter(|(_, &count)| count == 0)
-                .nth(2)
-                .is_some()
-        }
+        ending_pile_empty
+            || self.piles.values().filter(|&&count| count == 0).count()
+                >= empty_piles_to_end(self.num_players)
     }
 
-    pub fn iter(&self) -> Iter {
-        Iter {
-            supply: self,
-            cur: 0,
-        }
+    /// Returns each card and its count in the order they are shown.
+    pub fn iter(&self) -> impl Iterator<Item = (CardKind, &usize)> {
+        self.cards
+            .iter()
+            .map(move |card| (*card, &self.piles[card]))
     }
 
-    pub fn iter_mut(&mut self) -> IterMut {
-        IterMut {
-            supply: self,
-            cur: 0,
-        }
+    /// Returns each card and its count in no particular order.
+    #[cfg(test)]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (CardKind, &mut usize)> {
+        self.piles.iter_mut().map(|(&card, count)| (card, count))
     }
 
-    /// Increments supply count for card as position `index` by `count` and
-    /// returns the card.
+    /// Increments supply count for `card` by `count` and returns the card.
     ///
     /// # Errors
     ///
-    /// If `index` is out of bounds, or supply count would be less than 0, then
-    /// an error is returned.
-    fn add_count(&mut self, index: usize, count: i32) -> Result<CardKind> {
-        let (card, supply_count) = self.get_entry_mut(index).ok_or(Error::InvalidIndex)?;
+    /// If `card` is not in the supply, or supply count would be less than 0,
+    /// then an error is returned.
+    fn add_count(&mut self, card: CardKind, count: i32) -> Result<CardKind> {
+        let supply_count = self.piles.get_mut(&card).ok_or(Error::InvalidCard)?;
         let sum = i32::try_from(*supply_count).unwrap() + count;
         if sum >= 0 {
             *supply_count = usize::try_from(sum).unwrap();
@@ -124,61 +218,13 @@ impl Supply {
     }
 }
 
-pub struct Iter<'a> {
-    supply: &'a Supply,
-    cur: usize,
-}
-
-impl<'a> Iterator for Iter<'a> {
-    type Item = EntryRef<'a>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let entry = self.supply.get_entry(self.cur);
-        self.cur += 1;
-        entry
-    }
-}
-
-pub struct IterMut<'a> {
-    supply: &'a mut Supply,
-    cur: usize,
-}
-
-impl<'a> Iterator for IterMut<'a> {
-    type Item = EntryMut<'a>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let entry = self.supply.get_entry_mut(self.cur);
-        self.cur += 1;
-        entry.map(|(card, count)| unsafe { (card, &mut *(count as *mut _)) })
-    }
-}
-
-impl<'a> IntoIterator for &'a Supply {
-    type Item = EntryRef<'a>;
-    type IntoIter = Iter<'a>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
-    }
-}
-
-impl<'a> IntoIterator for &'a mut Supply {
-    type Item = EntryMut<'a>;
-    type IntoIter = IterMut<'a>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
-    }
-}
-
 impl Location for Supply {
     fn get(&self, index: usize) -> Option<CardKind> {
-        self.get_entry(index).map(|(k, _)| k)
+        self.cards.get(index).copied()
     }
 
     fn find(&self, card: CardKind) -> Option<usize> {
-        self.iter().position(|(k, _)| k == card)
+        self.cards.iter().position(|&k| k == card)
     }
 
     /// Reduces the count and returns the card at position `index`.
@@ -187,7 +233,8 @@ impl Location for Supply {
     ///
     /// Panics if `index` is out of bounds, or if count is 0.
     fn remove_unchecked(&mut self, index: usize) -> CardKind {
-        let (card, count) = self.get_entry_mut(index).expect("index is out of bounds");
+        let card = self.cards[index];
+        let count = self.piles.get_mut(&card).unwrap();
 
         if *count == 0 {
             panic!("can't reduce count past 0")
@@ -203,7 +250,9 @@ impl Location for Supply {
     ///
     /// If `index` is out of bounds, or if count is 0, then an error is returned.
     fn remove(&mut self, index: usize) -> Result<CardKind> {
-        self.add_count(index, -1)
+        let card = self.get(index).ok_or(Error::InvalidIndex)?;
+
+        self.add_count(card, -1)
     }
 
     /// Reduces the count for `card` and returns `card`.
@@ -212,9 +261,7 @@ impl Location for Supply {
     ///
     /// If `card` is not contained in `self`, or if count is 0, then an error is returned.
     fn remove_card(&mut self, card: CardKind) -> Result<CardKind> {
-        self.find(card)
-            .ok_or(Error::InvalidCard)
-            .and_then(|i| self.add_count(i, -1))
+        self.add_count(card, -1)
     }
 
     /// Increments count for `card` and returns `card`.
@@ -223,9 +270,7 @@ impl Location for Supply {
     ///
     /// If `card` if not in `self`, then an error is returned.
     fn add_card(&mut self, card: CardKind) -> Result<CardKind> {
-        self.find(card)
-            .ok_or(Error::InvalidCard)
-            .and_then(|i| self.add_count(i, 1))
+        self.add_count(card, 1)
     }
 
     /// Moves all cards specified by `indices` from `self` to `other`.
@@ -236,23 +281,23 @@ impl Location for Supply {
     /// count would be reduced past 0 for the card specified by an element in
     /// `indices`, then an error is returned.
     fn move_all(&mut self, other: &mut impl Location, indices: &[usize]) -> Result<()> {
-        // Find any runs in `indices`
-        let mut runs = HashMap::new();
-        for i in indices {
-            *runs.entry(i).or_insert(0) += 1;
+        // Find any runs in `indices`, in the order they are first given.
+        let mut runs: Vec<(CardKind, usize)> = Vec::new();
+        for &index in indices {
+            let card = self.get(index).ok_or(Error::InvalidIndex)?;
+
+            match runs.iter_mut().find(|(k, _)| *k == card) {
+                Some((_, count)) => *count += 1,
+                None => runs.push((card, 1)),
+            }
         }
 
-        let valid_indices = runs.iter().all(|(&&index, &count)| {
-            self.get_entry(index)
-                .map_or(false, |(_, &supply_count)| supply_count >= count)
-        });
+        let valid_indices = runs.iter().all(|&(card, count)| self.piles[&card] >= count);
 
         if valid_indices {
-            for (&index, count) in runs {
-                self.add_count(index, -(i32::try_from(count).unwrap()))
-                    .unwrap();
+            for (card, count) in runs {
+                *self.piles.get_mut(&card).unwrap() -= count;
 
-                let card = self.get(index).unwrap();
                 for _ in 0..count {
                     // `other` should not be a supply so `add_card` should not fail.
                     other.add_card(card).unwrap();
@@ -276,62 +321,281 @@ impl Location for Supply {
         let indices = cards
             .iter()
             .map(|&card| self.find(card).ok_or(Error::InvalidCard))
-            .collect::<std::result::Result<Vec<_>, _>>()?;
+            .collect::<Result<Vec<_>>>()?;
 
         self.move_all(other, &indices).or(Err(Error::InvalidCard))
     }
 }
 
+impl fmt::Debug for Supply {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Piles are shown in order rather than in the order of the map.
+        f.debug_struct("Supply")
+            .field("piles", &self.iter().collect::<Vec<_>>())
+            .field("num_kingdom_cards", &self.num_kingdom_cards)
+            .field("ending_piles", &self.ending_piles)
+            .field("num_players", &self.num_players)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dominion::location::CardVec;
     use crate::dominion::KingdomSet;
 
-    impl Supply {
-        fn set_count(&mut self, card: CardKind, count: usize) {
-            let (_, supply_count) = self.find(card).and_then(|i| self.get_entry_mut(i)).unwrap();
+    fn new_supply(num_players: usize, piles: &PileConfig) -> Supply {
+        Supply::new(&KingdomSet::FirstGame.cards(), num_players, piles).unwrap()
+    }
+
+    /// Takes every card from the pile for `card`.
+    fn empty_pile(supply: &mut Supply, card: CardKind) {
+        while supply.remove_card(card).is_ok() {}
+        assert_eq!(supply.count(card), Some(0));
+    }
 
-            *supply_count = count;
+    /// Piles standing in for Colony and Platinum, which are not implemented.
+    fn colony_piles() -> PileConfig {
+        PileConfig {
+            extra: vec![(CardKind::Gardens, 8), (CardKind::Witch, 12)],
+            ending: vec![CardKind::Province, CardKind::Gardens],
         }
     }
 
     #[test]
     fn game_not_over_full_supply() {
-        let s = Supply::new(KingdomSet::FirstGame.cards(), 2);
+        let s = new_supply(2, &PileConfig::default());
+        assert!(!s.is_game_over());
 
+        let s = new_supply(2, &colony_piles());
         assert!(!s.is_game_over());
     }
 
     #[test]
     fn game_over_empty_province_pile() {
-        let mut s = Supply::new(KingdomSet::FirstGame.cards(), 2);
-        s.set_count(CardKind::Province, 0);
+        let mut s = new_supply(2, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Province);
         assert!(s.is_game_over());
     }
 
+    #[test]
+    fn game_over_empty_province_pile_with_6_players() {
+        let mut s = new_supply(6, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Province);
+        assert!(s.is_game_over());
+    }
+
+    #[test]
+    fn game_not_over_empty_duchy_pile() {
+        let mut s = new_supply(2, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Duchy);
+        assert!(!s.is_game_over());
+    }
+
+    #[test]
+    fn game_over_empty_extra_ending_pile() {
+        let mut s = new_supply(2, &colony_piles());
+        empty_pile(&mut s, CardKind::Witch);
+        assert!(!s.is_game_over());
+        empty_pile(&mut s, CardKind::Gardens);
+        assert!(s.is_game_over());
+    }
+
+    #[test]
+    fn game_over_empty_province_pile_with_extra_ending_pile() {
+        let mut s = new_supply(2, &colony_piles());
+        empty_pile(&mut s, CardKind::Province);
+        assert!(s.is_game_over());
+    }
+
+    #[test]
+    fn game_not_over_without_ending_piles() {
+        let piles = PileConfig {
+            extra: Vec::new(),
+            ending: Vec::new(),
+        };
+        let mut s = new_supply(2, &piles);
+        empty_pile(&mut s, CardKind::Province);
+        assert!(!s.is_game_over());
+    }
+
     #[test]
     fn game_not_over_2_empty_piles() {
-        let mut s = Supply::new(KingdomSet::FirstGame.cards(), 2);
-        s.set_count(CardKind::Copper, 0);
-        s.set_count(CardKind::Cellar, 0);
+        let mut s = new_supply(2, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Copper);
+        empty_pile(&mut s, CardKind::Cellar);
         assert!(!s.is_game_over());
     }
 
     #[test]
     fn game_over_3_empty_piles() {
-        let mut s = Supply::new(KingdomSet::FirstGame.cards(), 2);
-        s.set_count(CardKind::Copper, 0);
-        s.set_count(CardKind::Cellar, 0);
-        s.set_count(CardKind::Militia, 0);
+        let mut s = new_supply(2, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Copper);
+        empty_pile(&mut s, CardKind::Cellar);
+        empty_pile(&mut s, CardKind::Militia);
+        assert!(s.is_game_over());
+    }
+
+    #[test]
+    fn game_over_3_empty_piles_including_curse() {
+        let mut s = new_supply(2, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Curse);
+        empty_pile(&mut s, CardKind::Cellar);
+        assert!(!s.is_game_over());
+        empty_pile(&mut s, CardKind::Militia);
+        assert!(s.is_game_over());
+    }
+
+    #[test]
+    fn game_over_3_empty_piles_including_extra_pile() {
+        let mut s = new_supply(4, &colony_piles());
+        empty_pile(&mut s, CardKind::Witch);
+        empty_pile(&mut s, CardKind::Cellar);
+        assert!(!s.is_game_over());
+        empty_pile(&mut s, CardKind::Militia);
         assert!(s.is_game_over());
     }
 
+    #[test]
+    fn game_over_4_empty_piles_with_5_players() {
+        let mut s = new_supply(5, &PileConfig::default());
+        empty_pile(&mut s, CardKind::Copper);
+        empty_pile(&mut s, CardKind::Cellar);
+        empty_pile(&mut s, CardKind::Militia);
+        assert!(!s.is_game_over());
+        empty_pile(&mut s, CardKind::Market);
+        assert!(s.is_game_over());
+    }
+
+    #[test]
+    fn extra_piles_follow_base_cards() {
+        let s = new_supply(2, &colony_piles());
+        let cards: Vec<_> = s.iter().map(|(card, &count)| (card, count)).collect();
+
+        assert_eq!(s.kingdom(), &KingdomSet::FirstGame.cards()[..]);
+        assert_eq!(cards.len(), 19);
+        assert_eq!(cards[16], (CardKind::Curse, 10));
+        assert_eq!(cards[17..], [(CardKind::Gardens, 8), (CardKind::Witch, 12)]);
+        assert_eq!(s.find(CardKind::Witch), Some(18));
+    }
+
+    #[test]
+    fn invalid_piles() {
+        let invalid = |extra: Vec<(CardKind, usize)>, ending: Vec<CardKind>| {
+            Supply::new(
+                &KingdomSet::FirstGame.cards(),
+                2,
+                &PileConfig { extra, ending },
+            )
+            .map(|_| ())
+        };
+
+        // Extra piles that repeat a base, kingdom or extra pile.
+        assert_eq!(
+            invalid(vec![(CardKind::Gold, 12)], vec![]),
+            Err(Error::InvalidCard)
+        );
+        assert_eq!(
+            invalid(vec![(CardKind::Cellar, 10)], vec![]),
+            Err(Error::InvalidCard)
+        );
+        assert_eq!(
+            invalid(vec![(CardKind::Witch, 10), (CardKind::Witch, 10)], vec![]),
+            Err(Error::InvalidCard)
+        );
+
+        // Ending piles that are not in the supply or are listed twice.
+        assert_eq!(
+            invalid(vec![], vec![CardKind::Witch]),
+            Err(Error::InvalidCard)
+        );
+        assert_eq!(
+            invalid(vec![], vec![CardKind::Province, CardKind::Province]),
+            Err(Error::InvalidCard)
+        );
+    }
+
+    #[test]
+    fn invalid_kingdom() {
+        let mut kingdom = KingdomSet::FirstGame.cards();
+        kingdom[0] = CardKind::Copper;
+
+        assert_eq!(
+            Supply::new(&kingdom, 2, &PileConfig::default()).map(|_| ()),
+            Err(Error::InvalidCard)
+        );
+    }
+
+    #[test]
+    fn base_card_sizes() {
+        let sizes = |num_players| {
+            let s = new_supply(num_players, &PileConfig::default());
+            BASE_CARDS
+                .iter()
+                .map(|&(card, _)| s.count(card).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // Copper, Silver, Gold, Estate, Duchy, Province and Curse.
+        assert_eq!(sizes(2), vec![46, 40, 30, 8, 8, 8, 10]);
+        assert_eq!(sizes(3), vec![39, 40, 30, 12, 12, 12, 20]);
+        assert_eq!(sizes(4), vec![32, 40, 30, 12, 12, 12, 30]);
+        assert_eq!(sizes(5), vec![85, 80, 60, 12, 12, 15, 40]);
+        assert_eq!(sizes(6), vec![78, 80, 60, 12, 12, 18, 50]);
+    }
+
+    #[test]
+    fn invalid_player_count() {
+        for &num_players in &[0, 1, 7] {
+            assert_eq!(
+                Supply::new(
+                    &KingdomSet::FirstGame.cards(),
+                    num_players,
+                    &PileConfig::default()
+                )
+                .map(|_| ()),
+                Err(Error::InvalidPlayerCount)
+            );
+        }
+    }
+
+    #[test]
+    fn count_by_card() {
+        let s = new_supply(3, &PileConfig::default());
+        assert_eq!(s.count(CardKind::Province), Some(12));
+        assert_eq!(s.count(CardKind::Cellar), Some(10));
+        assert_eq!(s.count(CardKind::Witch), None);
+    }
+
+    #[test]
+    fn move_cards_by_index_and_card() {
+        let mut s = new_supply(2, &colony_piles());
+        let mut cards = CardVec::new();
+
+        let witch = s.find(CardKind::Witch).unwrap();
+        s.move_all(&mut cards, &[witch, witch]).unwrap();
+        s.move_all_cards(&mut cards, &[CardKind::Gold]).unwrap();
+
+        assert_eq!(
+            cards,
+            CardVec(vec![CardKind::Witch, CardKind::Witch, CardKind::Gold])
+        );
+        assert_eq!(s.count(CardKind::Witch), Some(10));
+        assert_eq!(s.count(CardKind::Gold), Some(29));
+        assert_eq!(s.move_all(&mut cards, &[19]), Err(Error::InvalidIndex));
+        assert_eq!(
+            s.move_all_cards(&mut cards, &[CardKind::Bandit]),
+            Err(Error::InvalidCard)
+        );
+    }
+
     #[test]
     fn test_kingdom_card_size_regular_card() {
         let regular_card = CardKind::Cellar;
         assert!(!regular_card.is_victory());
 
-        for num_players in 2..5 {
+        for num_players in 2..7 {
             assert_eq!(kingdom_card_size(regular_card, num_players), 10);
         }
     }
@@ -344,5 +608,6 @@ mod tests {
         assert_eq!(kingdom_card_size(victory_card, 2), 8);
         assert_eq!(kingdom_card_size(victory_card, 3), 12);
         assert_eq!(kingdom_card_size(victory_card, 4), 12);
+        assert_eq!(kingdom_card_size(victory_card, 6), 12);
     }
 }
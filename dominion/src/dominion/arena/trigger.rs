@@ -0,0 +1,40 @@
+use crate::dominion::{Arena, CardKind};
+
+/// A trigger is registered by a card effect and is checked every time a card is
+/// played for the remainder of the turn. The trigger function returns `true`
+/// when it has fired and should be removed from the registry.
+pub(super) struct Trigger(pub(super) TriggerFunction);
+
+type TriggerFunction = fn(arena: &mut Arena, card: CardKind) -> bool;
+
+impl std::fmt::Debug for Trigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Trigger")
+    }
+}
+
+impl PartialEq for Trigger {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as *const () == other.0 as *const ()
+    }
+}
+
+impl Arena {
+    pub(super) fn register_trigger(&mut self, trigger: &'static Trigger) {
+        self.triggers.push(trigger);
+    }
+
+    /// Calls every registered trigger with the played `card` and removes the
+    /// triggers that have fired.
+    pub(super) fn fire_triggers(&mut self, card: CardKind) {
+        // Triggers require a mutable reference to the arena so the registry is
+        // moved out while they are called.
+        let mut triggers = std::mem::replace(&mut self.triggers, Vec::new());
+
+        triggers.retain(|trigger| !(trigger.0)(self, card));
+
+        // Keep any triggers that were registered while firing.
+        triggers.append(&mut self.triggers);
+        self.triggers = triggers;
+    }
+}
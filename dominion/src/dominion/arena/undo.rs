@@ -0,0 +1,317 @@
+use super::effect::CardActionQueue;
+use super::player::Player;
+use super::supply::Supply;
+use super::trigger::Trigger;
+use crate::dominion::event::GameEvent;
+use crate::dominion::location::CardVec;
+use crate::dominion::turn::Turn;
+use crate::dominion::types::{Error, Result};
+use crate::dominion::Arena;
+use rand_pcg::Pcg64Mcg;
+
+/// Game state before a move was made.
+#[derive(Clone)]
+struct Snapshot {
+    supply: Supply,
+    trash: CardVec,
+    players: Vec<Player>,
+    turn: Turn,
+    current_player_id: usize,
+    actions: CardActionQueue,
+    triggers: Vec<&'static Trigger>,
+    rng: Pcg64Mcg,
+    event_count: usize,
+}
+
+/// Snapshots of the moves that can be undone, with the most recent move last.
+#[derive(Clone, Default)]
+pub(super) struct UndoHistory {
+    snapshots: Vec<Snapshot>,
+    // Set for arenas that are only searched, where moves are never undone.
+    disabled: bool,
+}
+
+impl UndoHistory {
+    /// Returns a history that never records moves.
+    pub(super) fn disabled() -> Self {
+        Self {
+            snapshots: Vec::new(),
+            disabled: true,
+        }
+    }
+}
+
+impl std::fmt::Debug for UndoHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "UndoHistory({} moves)", self.snapshots.len())
+    }
+}
+
+impl Snapshot {
+    fn new(arena: &Arena) -> Self {
+        Self {
+            supply: arena.supply.clone(),
+            trash: arena.trash.clone(),
+            players: arena.players.clone(),
+            turn: arena.turn,
+            current_player_id: arena.current_player_id,
+            actions: arena.actions.clone(),
+            triggers: arena.triggers.clone(),
+            rng: arena.rng.clone(),
+            event_count: arena.log.events.len(),
+        }
+    }
+
+    /// Returns `true` if a move from this state to `arena` gave a player
+    /// information they did not have before.
+    fn is_revealed(&self, arena: &Arena) -> bool {
+        let current_player_id = self.current_player_id;
+        let revealed = arena.log.events[self.event_count..]
+            .iter()
+            .any(|event| match *event {
+                GameEvent::Draw { .. } | GameEvent::Shuffle { .. } | GameEvent::Reveal { .. } => {
+                    true
+                }
+                // Cards discarded by another player, such as those discarded
+                // for Militia, are seen by every player.
+                GameEvent::Discard { player_id, .. } => player_id != current_player_id,
+                _ => false,
+            });
+
+        // Cards taken from a draw pile, such as those looked at by Sentry,
+        // are seen by a player even if they are not drawn.
+        revealed
+            || self
+                .players
+                .iter()
+                .zip(&arena.players)
+                .any(|(before, after)| after.draw_pile.len() < before.draw_pile.len())
+    }
+}
+
+impl Arena {
+    /// Reverts the most recent move made in the current turn. Events recorded
+    /// by the move are removed from the event log.
+    ///
+    /// # Errors
+    ///
+    /// If no move has been made this turn, or if the most recent move drew,
+    /// shuffled or revealed any cards, then an error is returned.
+    pub fn undo(&mut self) -> Result<()> {
+        let snapshot = self.history.snapshots.pop().ok_or(Error::NothingToUndo)?;
+
+        self.supply = snapshot.supply;
+        self.trash = snapshot.trash;
+        self.players = snapshot.players;
+        self.turn = snapshot.turn;
+        self.current_player_id = snapshot.current_player_id;
+        self.actions = snapshot.actions;
+        self.triggers = snapshot.triggers;
+        self.rng = snapshot.rng;
+        self.log.events.truncate(snapshot.event_count);
+
+        Ok(())
+    }
+
+    /// Makes a move for `player_id` with `f` so that it can be undone. Only
+    /// moves made by the current player during their turn are recorded.
+    /// Rejected moves are not recorded, and moves that reveal information,
+    /// any move by another player and the end of a turn clear the history.
+    pub(super) fn undoable(
+        &mut self,
+        player_id: usize,
+        f: impl FnOnce(&mut Self) -> Result<()>,
+    ) -> Result<()> {
+        if self.history.disabled {
+            return f(self);
+        }
+
+        // Another player's decision can change the game without emitting an
+        // event, such as declining to reveal a Reaction, so it is never
+        // undone even if it is rejected.
+        if player_id != self.current_player_id {
+            self.history.snapshots.clear();
+            return f(self);
+        }
+
+        let snapshot = Snapshot::new(self);
+        let result = f(self);
+
+        // Rejected moves by the current player leave the game unchanged and
+        // never emit an event.
+        let changed = result.is_ok() || (self.log.events.len() > snapshot.event_count);
+
+        if !changed {
+            return result;
+        }
+
+        if (self.current_player_id != snapshot.current_player_id) || snapshot.is_revealed(self) {
+            self.history.snapshots.clear();
+        } else {
+            self.history.snapshots.push(snapshot);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::dominion::location::{CardVec, Location};
+    use crate::dominion::turn::Turn;
+    use crate::dominion::types::Error;
+    use crate::dominion::{Arena, CardKind, KingdomSet};
+
+    fn setup_buy_phase() -> Arena {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+
+        arena.current_player_mut().hand.clear();
+        arena.current_player_mut().hand.push(CardKind::Gold);
+        arena.end_turn_phase().unwrap();
+        arena.play_card(CardKind::Gold).unwrap();
+
+        arena
+    }
+
+    #[test]
+    fn undo_buy() {
+        let mut arena = setup_buy_phase();
+        let turn = arena.turn();
+        let event_count = arena.events().len();
+
+        arena.buy_card(CardKind::Silver).unwrap();
+
+        assert_eq!(arena.undo(), Ok(()));
+        assert_eq!(arena.turn(), turn);
+        assert_eq!(arena.events().len(), event_count);
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+        assert_eq!(arena.supply.count(CardKind::Silver), Some(40));
+    }
+
+    #[test]
+    fn undo_every_move_in_turn() {
+        let mut arena = setup_buy_phase();
+
+        arena.buy_card(CardKind::Silver).unwrap();
+
+        assert_eq!(arena.undo(), Ok(()));
+        assert_eq!(arena.undo(), Ok(()));
+        assert_eq!(arena.current_player().hand, cardvec![CardKind::Gold]);
+        assert_eq!(arena.undo(), Ok(()));
+        assert!(match arena.turn() {
+            Turn::Action(_) => true,
+            _ => false,
+        });
+        assert_eq!(arena.undo(), Err(Error::NothingToUndo));
+    }
+
+    #[test]
+    fn undo_rejected_move() {
+        let mut arena = setup_buy_phase();
+
+        arena.buy_card(CardKind::Silver).unwrap();
+        assert_eq!(arena.buy_card(CardKind::Silver), Err(Error::NoMoreBuys));
+
+        assert_eq!(arena.undo(), Ok(()));
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+    }
+
+    #[test]
+    fn undo_pending_decision() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+
+        arena.current_player_mut().hand.push(CardKind::Workshop);
+        arena.play_card(CardKind::Workshop).unwrap_err();
+        arena
+            .select_cards(arena.current_player_id, &[CardKind::Silver])
+            .unwrap();
+        assert_eq!(arena.pending_decision(), None);
+
+        assert_eq!(arena.undo(), Ok(()));
+        assert!(arena.pending_decision().is_some());
+        assert_eq!(arena.current_player().discard_pile, cardvec![]);
+
+        assert_eq!(arena.undo(), Ok(()));
+        assert_eq!(arena.pending_decision(), None);
+        assert!(arena
+            .current_player()
+            .hand
+            .find(CardKind::Workshop)
+            .is_some());
+    }
+
+    #[test]
+    fn cannot_undo_draw() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+
+        arena.current_player_mut().hand.push(CardKind::Smithy);
+        arena.play_card(CardKind::Smithy).unwrap();
+
+        assert_eq!(arena.undo(), Err(Error::NothingToUndo));
+    }
+
+    #[test]
+    fn cannot_undo_other_players_selection() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+
+        arena.current_player_mut().hand.push(CardKind::Militia);
+        arena.play_card(CardKind::Militia).unwrap_err();
+
+        let discarded = arena.players[1].hand[..2].to_vec();
+        arena.select_cards(1, &discarded).unwrap();
+
+        assert_eq!(arena.undo(), Err(Error::NothingToUndo));
+        assert!(arena
+            .current_player()
+            .play_zone
+            .find(CardKind::Militia)
+            .is_some());
+    }
+
+    #[test]
+    fn cannot_undo_declined_reaction() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+
+        arena.players[1].hand.push(CardKind::Moat);
+        arena.current_player_mut().hand.push(CardKind::Militia);
+        arena.play_card(CardKind::Militia).unwrap_err();
+        assert!(arena.players[1].reacting);
+
+        // Declining to reveal Moat emits no event but moves on to the discard.
+        assert!(arena.select_cards(1, &[]).is_err());
+        assert!(!arena.players[1].reacting);
+
+        assert_eq!(arena.undo(), Err(Error::NothingToUndo));
+        assert!(arena
+            .current_player()
+            .play_zone
+            .find(CardKind::Militia)
+            .is_some());
+    }
+
+    #[test]
+    fn cannot_undo_previous_turn_without_drawing() {
+        let mut arena = setup_buy_phase();
+
+        // Nothing is drawn during cleanup when the player has no cards left.
+        let player = arena.current_player_mut();
+        player.draw_pile.clear();
+        player.discard_pile.clear();
+        player.hand.clear();
+        player.play_zone.clear();
+
+        arena.end_turn_phase().unwrap();
+        assert_eq!(arena.current_player_id, 1);
+
+        assert_eq!(arena.undo(), Err(Error::NothingToUndo));
+    }
+
+    #[test]
+    fn cannot_undo_previous_turn() {
+        let mut arena = setup_buy_phase();
+
+        arena.end_turn_phase().unwrap();
+
+        assert_eq!(arena.undo(), Err(Error::NothingToUndo));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::dominion::CardKind;
+use std::convert::TryFrom;
+
+/// Returns the victory points that `card` is worth at the end of the game when
+/// it is part of `deck`. Cards that are worth a variable amount are computed
+/// here and all other cards use the fixed amount from the card text.
+pub(super) fn victory_points(card: CardKind, deck: &[CardKind]) -> i32 {
+    match card {
+        // "Worth 1 Victory for every 10 cards in your deck (rounded down)."
+        CardKind::Gardens => i32::try_from(deck.len() / 10).unwrap(),
+        _ => card.victory_points(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_victory_points() {
+        let deck = vec![CardKind::Copper; 20];
+
+        assert_eq!(victory_points(CardKind::Estate, &deck), 1);
+        assert_eq!(victory_points(CardKind::Province, &deck), 6);
+        assert_eq!(victory_points(CardKind::Curse, &deck), -1);
+        assert_eq!(victory_points(CardKind::Copper, &deck), 0);
+    }
+
+    #[test]
+    fn gardens_rounds_down() {
+        assert_eq!(
+            victory_points(CardKind::Gardens, &vec![CardKind::Copper; 9]),
+            0
+        );
+        assert_eq!(
+            victory_points(CardKind::Gardens, &vec![CardKind::Copper; 10]),
+            1
+        );
+        assert_eq!(
+            victory_points(CardKind::Gardens, &vec![CardKind::Copper; 29]),
+            2
+        );
+        assert_eq!(
+            victory_points(CardKind::Gardens, &vec![CardKind::Copper; 30]),
+            3
+        );
+    }
+}
@@ -11,6 +11,7 @@ pub(crate) enum ParseCommandError {
     UnspecifiedPlayerId,
     UndefinedCardKind,
     UnspecifiedCardKind,
+    UnspecifiedPath,
 }
 
 impl Error for ParseCommandError {
@@ -21,6 +22,7 @@ impl Error for ParseCommandError {
             ParseCommandError::UnspecifiedPlayerId => "no player id arg to parse",
             ParseCommandError::UndefinedCardKind => "failed to parse card arg",
             ParseCommandError::UnspecifiedCardKind => "no card arg to parse",
+            ParseCommandError::UnspecifiedPath => "no file path arg to parse",
         }
     }
 }
@@ -46,10 +48,13 @@ impl From<std::num::ParseIntError> for ParseCommandError {
 #[derive(Debug, PartialEq)]
 pub(crate) enum Command {
     View(Location),
+    ListMoves,
     EndPhase,
     PlayCard(CardKind),
     BuyCard(CardKind),
     SelectCards(CardVec),
+    Save(String),
+    Undo,
 }
 
 impl FromStr for Command {
@@ -62,7 +67,9 @@ impl FromStr for Command {
             .ok_or(ParseCommandError::InvalidCommand)?;
 
         match command {
+            "moves" => Ok(Command::ListMoves),
             "end" => Ok(Command::EndPhase),
+            "undo" => Ok(Command::Undo),
             "play" => Ok(Command::PlayCard(
                 args.get(0)
                     .ok_or(ParseCommandError::UnspecifiedCardKind)?
@@ -81,6 +88,11 @@ impl FromStr for Command {
 
                 Ok(Command::SelectCards(cards))
             }
+            "save" => Ok(Command::Save(
+                args.get(0)
+                    .ok_or(ParseCommandError::UnspecifiedPath)?
+                    .to_string(),
+            )),
             "supply" => Ok(Command::View(Location::Supply)),
             "hand" => Ok(Command::View(Location::Hand {
                 player_id: args
@@ -112,9 +124,12 @@ pub fn help() -> &'static str {
      discard-zone <i>- view player <i>'s discard pile\n\
      play-zone <i> - view player <i>'s play zone\n\
      supply - view the game's supply\n\
+     moves - list the current player's legal moves\n\
      end - ends the current phase (action or buy)\n\
      play <card>\n\
-     buy <card>"
+     buy <card>\n\
+     undo - undo the last move of the turn, unless it revealed any cards\n\
+     save <file> - save the game to <file>"
 }
 
 #[cfg(test)]
@@ -151,6 +166,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_list_moves_command() {
+        assert_eq!("moves".parse::<Command>().unwrap(), Command::ListMoves);
+    }
+
+    #[test]
+    fn parse_undo_command() {
+        assert_eq!("undo".parse::<Command>().unwrap(), Command::Undo);
+    }
+
+    #[test]
+    fn parse_save_command() {
+        assert_eq!(
+            "save game.json".parse::<Command>().unwrap(),
+            Command::Save("game.json".to_string())
+        );
+        assert_eq!(
+            "save".parse::<Command>().unwrap_err(),
+            ParseCommandError::UnspecifiedPath
+        );
+    }
+
     #[test]
     fn parse_end_phase_command() {
         assert_eq!("end".parse::<Command>().unwrap(), Command::EndPhase);
@@ -0,0 +1,511 @@
+use crate::dominion::agent::{self, Agent};
+use crate::dominion::arena;
+use crate::dominion::kingdom::Kingdom;
+use crate::dominion::{CardKind, KingdomSet};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 6;
+
+/// Setup of a new game. A configuration returned by `GameConfig::new` or
+/// `GameConfig::from_args` has been validated.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GameConfig {
+    /// Players ordered by player id.
+    pub players: Vec<PlayerConfig>,
+    pub kingdom: Kingdom,
+    #[serde(default)]
+    pub piles: PileConfig,
+    pub seed: u64,
+}
+
+/// Supply piles added to the kingdom and base cards, and the piles that end
+/// the game when empty.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PileConfig {
+    /// Piles such as Colony and Platinum with the number of cards in each.
+    pub extra: Vec<(CardKind, usize)>,
+    /// Piles that end the game as soon as one of them is empty, such as
+    /// Province and Colony.
+    pub ending: Vec<CardKind>,
+}
+
+impl Default for PileConfig {
+    /// No extra piles, and the game ends when the Province pile is empty.
+    fn default() -> Self {
+        Self {
+            extra: Vec::new(),
+            ending: vec![CardKind::Province],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PlayerConfig {
+    pub name: String,
+    pub seat: Seat,
+}
+
+/// Who makes the moves of a player.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Seat {
+    Human,
+    /// A built-in agent, given by its name.
+    Bot(String),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ConfigError {
+    InvalidPlayerCount(usize),
+    /// The number of names or seats differs from the number of players.
+    MismatchedPlayerCount,
+    EmptyName,
+    DuplicateName(String),
+    UnknownAgent(String),
+    UnknownKingdomSet(String),
+    UnknownCard(String),
+    /// The card is not a kingdom card or is listed twice.
+    InvalidCard(CardKind),
+    /// The card's extra pile repeats another pile, or its ending pile is not
+    /// in the supply.
+    InvalidPile(CardKind),
+    /// An explicit kingdom does not have exactly 10 cards.
+    InvalidKingdomSize(usize),
+    /// Both a kingdom set and an explicit kingdom were given.
+    ConflictingKingdoms,
+    UnknownOption(String),
+    MissingValue(String),
+    InvalidValue {
+        option: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::InvalidPlayerCount(n) => write!(
+                f,
+                "{} players can't play, only {} to {}",
+                n, MIN_PLAYERS, MAX_PLAYERS
+            ),
+            ConfigError::MismatchedPlayerCount => {
+                write!(f, "the number of names and seats must match the players")
+            }
+            ConfigError::EmptyName => write!(f, "player names can't be empty"),
+            ConfigError::DuplicateName(name) => write!(f, "{} is the name of two players", name),
+            ConfigError::UnknownAgent(name) => write!(
+                f,
+                "unknown seat {}, expected human or one of: {}",
+                name,
+                agent::AGENT_NAMES.join(", ")
+            ),
+            ConfigError::UnknownKingdomSet(name) => write!(f, "unknown kingdom set {}", name),
+            ConfigError::UnknownCard(name) => write!(f, "unknown card {}", name),
+            ConfigError::InvalidCard(card) => {
+                write!(f, "{:?} is not a kingdom card or is listed twice", card)
+            }
+            ConfigError::InvalidPile(card) => write!(
+                f,
+                "the {:?} pile repeats another pile or is not in the supply",
+                card
+            ),
+            ConfigError::InvalidKingdomSize(n) => {
+                write!(f, "a kingdom has 10 cards but {} were given", n)
+            }
+            ConfigError::ConflictingKingdoms => {
+                write!(f, "only one of --kingdom and --cards can be given")
+            }
+            ConfigError::UnknownOption(option) => write!(f, "unknown option {}", option),
+            ConfigError::MissingValue(option) => write!(f, "missing value for {}", option),
+            ConfigError::InvalidValue { option, value } => {
+                write!(f, "invalid value {} for {}", value, option)
+            }
+        }
+    }
+}
+
+impl PlayerConfig {
+    /// Returns a human player named after `player_id`.
+    pub fn human(player_id: usize) -> Self {
+        Self {
+            name: format!("Player {}", player_id),
+            seat: Seat::Human,
+        }
+    }
+}
+
+impl Seat {
+    /// Returns the agent that makes the moves of a bot, or `None` for a human.
+    pub fn agent(&self) -> Option<Box<dyn Agent>> {
+        match self {
+            Seat::Human => None,
+            Seat::Bot(name) => agent::by_name(name),
+        }
+    }
+}
+
+impl GameConfig {
+    /// Returns the configuration of a game between human players.
+    ///
+    /// # Errors
+    ///
+    /// If the kingdom or number of players is invalid, then an error is
+    /// returned.
+    #[allow(dead_code)]
+    pub fn new(kingdom: Kingdom, num_players: usize, seed: u64) -> Result<Self, ConfigError> {
+        let config = Self {
+            players: (0..num_players).map(PlayerConfig::human).collect(),
+            kingdom,
+            piles: PileConfig::default(),
+            seed,
+        };
+
+        config.validate().map(|_| config)
+    }
+
+    /// Parses command line options. Lists are separated by commas.
+    ///
+    /// ```text
+    /// --players <n>      number of players, from 2 to 6
+    /// --names <names>    name of each player
+    /// --seats <seats>    human or the name of an agent for each player
+    /// --kingdom <set>    a recommended kingdom, FirstGame by default
+    /// --cards <cards>    the 10 kingdom cards
+    /// --seed <seed>      seed of the shuffles, random by default
+    /// ```
+    ///
+    /// The number of players defaults to the number of names or seats, and
+    /// otherwise to 2. Players are human unless seated otherwise.
+    ///
+    /// # Errors
+    ///
+    /// If an option can't be parsed, or if the resulting configuration is
+    /// invalid, then an error is returned.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Result<Self, ConfigError> {
+        let mut num_players = None;
+        let mut names = None;
+        let mut seats = None;
+        let mut kingdom = None;
+        let mut seed = None;
+        let mut args = args;
+
+        while let Some(option) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| ConfigError::MissingValue(option.clone()))?;
+            let invalid = || ConfigError::InvalidValue {
+                option: option.clone(),
+                value: value.clone(),
+            };
+            let list = || value.split(',').map(str::to_string).collect::<Vec<_>>();
+
+            match option.as_str() {
+                "--players" => num_players = Some(value.parse().map_err(|_| invalid())?),
+                "--names" => names = Some(list()),
+                "--seats" => {
+                    seats = Some(
+                        list()
+                            .into_iter()
+                            .map(|seat| match seat.as_str() {
+                                "human" => Seat::Human,
+                                _ => Seat::Bot(seat),
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                }
+                "--kingdom" | "--cards" if kingdom.is_some() => {
+                    return Err(ConfigError::ConflictingKingdoms)
+                }
+                "--kingdom" => {
+                    let set: KingdomSet = value
+                        .parse()
+                        .map_err(|_| ConfigError::UnknownKingdomSet(value.clone()))?;
+                    kingdom = Some(set.cards());
+                }
+                "--cards" => kingdom = Some(parse_kingdom(&list())?),
+                "--seed" => seed = Some(value.parse().map_err(|_| invalid())?),
+                _ => return Err(ConfigError::UnknownOption(option)),
+            }
+        }
+
+        let num_players = num_players
+            .or_else(|| names.as_ref().map(Vec::len))
+            .or_else(|| seats.as_ref().map(Vec::len))
+            .unwrap_or(MIN_PLAYERS);
+        let mut config = Self {
+            players: (0..num_players).map(PlayerConfig::human).collect(),
+            kingdom: kingdom.unwrap_or_else(|| KingdomSet::FirstGame.cards()),
+            piles: PileConfig::default(),
+            seed: seed.unwrap_or_else(rand::random),
+        };
+
+        if let Some(names) = names {
+            if names.len() != num_players {
+                return Err(ConfigError::MismatchedPlayerCount);
+            }
+            for (player, name) in config.players.iter_mut().zip(names) {
+                player.name = name;
+            }
+        }
+        if let Some(seats) = seats {
+            if seats.len() != num_players {
+                return Err(ConfigError::MismatchedPlayerCount);
+            }
+            for (player, seat) in config.players.iter_mut().zip(seats) {
+                player.seat = seat;
+            }
+        }
+
+        config.validate().map(|_| config)
+    }
+
+    /// Checks that the configuration describes a game that can be played.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let n = self.players.len();
+        if (n < MIN_PLAYERS) || (n > MAX_PLAYERS) {
+            return Err(ConfigError::InvalidPlayerCount(n));
+        }
+
+        for (i, player) in self.players.iter().enumerate() {
+            if player.name.is_empty() {
+                return Err(ConfigError::EmptyName);
+            }
+            if self.players[..i]
+                .iter()
+                .any(|other| other.name == player.name)
+            {
+                return Err(ConfigError::DuplicateName(player.name.clone()));
+            }
+            if let Seat::Bot(name) = &player.seat {
+                if agent::by_name(name).is_none() {
+                    return Err(ConfigError::UnknownAgent(name.clone()));
+                }
+            }
+        }
+
+        for (i, &card) in self.kingdom.iter().enumerate() {
+            if !CardKind::KINGDOM.contains(&card) || self.kingdom[..i].contains(&card) {
+                return Err(ConfigError::InvalidCard(card));
+            }
+        }
+
+        if let Some(card) = arena::invalid_pile(&self.kingdom, &self.piles) {
+            return Err(ConfigError::InvalidPile(card));
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_kingdom(names: &[String]) -> Result<Kingdom, ConfigError> {
+    if names.len() != 10 {
+        return Err(ConfigError::InvalidKingdomSize(names.len()));
+    }
+
+    let mut kingdom = [CardKind::Copper; 10];
+    for (card, name) in kingdom.iter_mut().zip(names) {
+        *card = name
+            .parse()
+            .map_err(|_| ConfigError::UnknownCard(name.clone()))?;
+    }
+
+    Ok(kingdom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_args(s: &str) -> Result<GameConfig, ConfigError> {
+        GameConfig::from_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn default_config() {
+        let config = from_args("--seed 3").unwrap();
+
+        assert_eq!(
+            config,
+            GameConfig::new(KingdomSet::FirstGame.cards(), 2, 3).unwrap()
+        );
+        assert_eq!(config.players[1], PlayerConfig::human(1));
+    }
+
+    #[test]
+    fn parse_players() {
+        let config =
+            from_args("--players 3 --names Ann,Bob,Cat --seats human,big-money,mcts").unwrap();
+
+        assert_eq!(
+            config.players,
+            vec![
+                PlayerConfig {
+                    name: "Ann".to_string(),
+                    seat: Seat::Human,
+                },
+                PlayerConfig {
+                    name: "Bob".to_string(),
+                    seat: Seat::Bot("big-money".to_string()),
+                },
+                PlayerConfig {
+                    name: "Cat".to_string(),
+                    seat: Seat::Bot("mcts".to_string()),
+                },
+            ]
+        );
+        assert!(config.players[0].seat.agent().is_none());
+        assert!(config.players[1].seat.agent().is_some());
+    }
+
+    #[test]
+    fn player_count_defaults_to_names_or_seats() {
+        assert_eq!(from_args("--names A,B,C,D").unwrap().players.len(), 4);
+        assert_eq!(
+            from_args("--seats human,human,human")
+                .unwrap()
+                .players
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn parse_kingdom_options() {
+        assert_eq!(
+            from_args("--kingdom DeckTop").unwrap().kingdom,
+            KingdomSet::DeckTop.cards()
+        );
+
+        let cards = KingdomSet::SilverAndGold.cards();
+        let names: Vec<_> = cards.iter().map(|card| format!("{:?}", card)).collect();
+
+        assert_eq!(
+            from_args(&format!("--cards {}", names.join(",")))
+                .unwrap()
+                .kingdom,
+            cards
+        );
+    }
+
+    #[test]
+    fn parse_seed() {
+        assert_eq!(from_args("--seed 42").unwrap().seed, 42);
+        assert_eq!(
+            from_args("--seed -1"),
+            Err(ConfigError::InvalidValue {
+                option: "--seed".to_string(),
+                value: "-1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_players() {
+        assert_eq!(
+            from_args("--players 1"),
+            Err(ConfigError::InvalidPlayerCount(1))
+        );
+        assert_eq!(
+            from_args("--players 7"),
+            Err(ConfigError::InvalidPlayerCount(7))
+        );
+        assert!(from_args("--players 6").is_ok());
+        assert_eq!(
+            from_args("--players 3 --names A,B"),
+            Err(ConfigError::MismatchedPlayerCount)
+        );
+        assert_eq!(
+            from_args("--names A,B --seats human,human,human"),
+            Err(ConfigError::MismatchedPlayerCount)
+        );
+        assert_eq!(
+            from_args("--names A,A"),
+            Err(ConfigError::DuplicateName("A".to_string()))
+        );
+        assert_eq!(from_args("--names A,"), Err(ConfigError::EmptyName));
+        assert_eq!(
+            from_args("--seats human,random"),
+            Err(ConfigError::UnknownAgent("random".to_string()))
+        );
+    }
+
+    #[test]
+    fn validate_piles() {
+        let mut config = GameConfig::new(KingdomSet::FirstGame.cards(), 2, 1).unwrap();
+        config.piles = PileConfig {
+            extra: vec![(CardKind::Witch, 12)],
+            ending: vec![CardKind::Province, CardKind::Witch],
+        };
+        assert_eq!(config.validate(), Ok(()));
+
+        config.piles.extra.push((CardKind::Cellar, 10));
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::InvalidPile(CardKind::Cellar))
+        );
+
+        config.piles.extra.pop();
+        config.piles.ending.push(CardKind::Bandit);
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::InvalidPile(CardKind::Bandit))
+        );
+    }
+
+    #[test]
+    fn piles_default_when_missing() {
+        let config = GameConfig::new(KingdomSet::FirstGame.cards(), 2, 1).unwrap();
+        let mut json = serde_json::to_value(&config).unwrap();
+        json.as_object_mut().unwrap().remove("piles");
+
+        assert_eq!(serde_json::from_value::<GameConfig>(json).unwrap(), config);
+    }
+
+    #[test]
+    fn invalid_kingdom() {
+        assert_eq!(
+            from_args("--kingdom LastGame"),
+            Err(ConfigError::UnknownKingdomSet("LastGame".to_string()))
+        );
+        assert_eq!(
+            from_args("--cards Cellar,Moat"),
+            Err(ConfigError::InvalidKingdomSize(2))
+        );
+        assert_eq!(
+            from_args(
+                "--cards Cellar,Moat,Village,Merchant,Workshop,Smithy,Remodel,Militia,Market,Gold"
+            ),
+            Err(ConfigError::InvalidCard(CardKind::Gold))
+        );
+        assert_eq!(
+            from_args(
+                "--cards Cellar,Moat,Village,Merchant,Workshop,Smithy,Remodel,Militia,Market,Moat"
+            ),
+            Err(ConfigError::InvalidCard(CardKind::Moat))
+        );
+        assert_eq!(
+            from_args(
+                "--cards Cellar,Moat,Village,Merchant,Workshop,Smithy,Remodel,Militia,Market,Mime"
+            ),
+            Err(ConfigError::UnknownCard("Mime".to_string()))
+        );
+        assert_eq!(
+            from_args("--kingdom DeckTop --kingdom FirstGame"),
+            Err(ConfigError::ConflictingKingdoms)
+        );
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert_eq!(
+            from_args("--players"),
+            Err(ConfigError::MissingValue("--players".to_string()))
+        );
+        assert_eq!(
+            from_args("--colour red"),
+            Err(ConfigError::UnknownOption("--colour".to_string()))
+        );
+    }
+}
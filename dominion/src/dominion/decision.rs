@@ -0,0 +1,113 @@
+use crate::dominion::CardKind;
+
+/// A choice that must be made before an action effect can resolve. Cards are
+/// chosen with `Arena::select_cards`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Decision {
+    /// Card text describing the choice.
+    pub description: &'static str,
+    pub chooser: Chooser,
+    /// Zone of the choosing player that cards are selected from.
+    pub zone: Zone,
+    pub count: CardCount,
+    /// Type of card that may be selected.
+    pub filter: CardFilter,
+    /// Maximum cost of a selected card, if limited.
+    pub max_cost: Option<CostBound>,
+    /// If `true`, selecting no cards is always allowed. Otherwise, no cards
+    /// may only be selected if there is no valid selection.
+    pub optional: bool,
+}
+
+/// Players that must make a decision.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Chooser {
+    CurrentPlayer,
+    /// Each other player makes their own selection.
+    OtherPlayers,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Zone {
+    Hand,
+    /// The card most recently drawn into the hand.
+    DrawnCard,
+    DiscardPile,
+    /// The card most recently put onto the discard pile.
+    TopOfDiscardPile,
+    /// Cards that have been revealed or set aside by the effect.
+    Stage,
+    Supply,
+}
+
+/// Number of cards that must be selected.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CardCount {
+    /// Exactly `n` cards, or fewer if fewer valid cards are available.
+    Exactly(usize),
+    /// Any number of cards from 0 to `n`.
+    UpTo(usize),
+    /// Any number of cards.
+    Any,
+    /// Every card in the zone, in the chosen order.
+    All,
+    /// Enough cards to leave `n` cards in the zone.
+    DownTo(usize),
+    /// A card for each empty supply pile.
+    PerEmptySupplyPile,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CardFilter {
+    Any,
+    Action,
+    Treasure,
+    Victory,
+    Reaction,
+    TreasureOtherThanCopper,
+    Card(CardKind),
+}
+
+impl CardFilter {
+    /// Returns `true` if `card` may be selected.
+    pub fn allows(self, card: CardKind) -> bool {
+        match self {
+            CardFilter::Any => true,
+            CardFilter::Action => card.is_action(),
+            CardFilter::Treasure => card.is_treasure(),
+            CardFilter::Victory => card.is_victory(),
+            CardFilter::Reaction => card.is_reaction(),
+            CardFilter::TreasureOtherThanCopper => card.is_treasure() && (card != CardKind::Copper),
+            CardFilter::Card(other) => card == other,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CostBound {
+    /// Costs up to a fixed amount.
+    UpTo(u8),
+    /// Costs up to the given amount more than the card trashed by the
+    /// previous choice.
+    UpToTrashedPlus(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_allows() {
+        assert!(CardFilter::Any.allows(CardKind::Curse));
+        assert!(CardFilter::Action.allows(CardKind::Moat));
+        assert!(!CardFilter::Action.allows(CardKind::Copper));
+        assert!(CardFilter::Treasure.allows(CardKind::Copper));
+        assert!(CardFilter::Victory.allows(CardKind::Gardens));
+        assert!(CardFilter::Reaction.allows(CardKind::Moat));
+        assert!(!CardFilter::Reaction.allows(CardKind::Militia));
+        assert!(CardFilter::TreasureOtherThanCopper.allows(CardKind::Silver));
+        assert!(!CardFilter::TreasureOtherThanCopper.allows(CardKind::Copper));
+        assert!(CardFilter::Card(CardKind::Copper).allows(CardKind::Copper));
+        assert!(!CardFilter::Card(CardKind::Copper).allows(CardKind::Silver));
+    }
+}
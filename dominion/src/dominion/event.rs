@@ -0,0 +1,57 @@
+use crate::dominion::turn::Turn;
+use crate::dominion::CardKind;
+use serde::{Deserialize, Serialize};
+
+/// A change to the state of a game. Events are recorded in the order they
+/// happen and include private information, such as the cards drawn by each
+/// player.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GameEvent {
+    Draw {
+        player_id: usize,
+        card: CardKind,
+    },
+    /// The player's discard pile is shuffled to form a new draw pile.
+    Shuffle {
+        player_id: usize,
+    },
+    Play {
+        player_id: usize,
+        card: CardKind,
+    },
+    Buy {
+        player_id: usize,
+        card: CardKind,
+    },
+    Gain {
+        player_id: usize,
+        card: CardKind,
+    },
+    Trash {
+        player_id: usize,
+        card: CardKind,
+    },
+    Discard {
+        player_id: usize,
+        card: CardKind,
+    },
+    Reveal {
+        player_id: usize,
+        card: CardKind,
+    },
+    /// A card is put on top of the player's draw pile.
+    PutOnDeck {
+        player_id: usize,
+        card: CardKind,
+    },
+    /// A card is set aside until the effect that set it aside is resolved.
+    SetAside {
+        player_id: usize,
+        card: CardKind,
+    },
+    /// The current player starts a new phase of their turn.
+    PhaseChange {
+        player_id: usize,
+        turn: Turn,
+    },
+}
@@ -1,17 +1,55 @@
 use crate::dominion::CardKind;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
-pub type Kingdom = &'static [CardKind; 10];
+// The randomizer is not used by the REPL.
+#[allow(dead_code)]
+mod randomizer;
+#[allow(unused_imports)]
+pub use self::randomizer::{KingdomError, Randomizer};
 
+/// The 10 kingdom cards used in a game.
+pub type Kingdom = [CardKind; 10];
+
+/// Recommended kingdoms from the rulebook that only use cards from the base
+/// set.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum KingdomSet {
     FirstGame,
+    SizeDistortion,
+    DeckTop,
+    SleightOfHand,
+    Improvements,
+    SilverAndGold,
 }
 
 impl KingdomSet {
+    /// Every kingdom set, in the order listed in the rulebook.
+    pub const ALL: &'static [KingdomSet] = &[
+        KingdomSet::FirstGame,
+        KingdomSet::SizeDistortion,
+        KingdomSet::DeckTop,
+        KingdomSet::SleightOfHand,
+        KingdomSet::Improvements,
+        KingdomSet::SilverAndGold,
+    ];
+
+    /// Returns the name of the kingdom set as printed in the rulebook.
+    pub fn name(self) -> &'static str {
+        match self {
+            KingdomSet::FirstGame => "First Game",
+            KingdomSet::SizeDistortion => "Size Distortion",
+            KingdomSet::DeckTop => "Deck Top",
+            KingdomSet::SleightOfHand => "Sleight of Hand",
+            KingdomSet::Improvements => "Improvements",
+            KingdomSet::SilverAndGold => "Silver & Gold",
+        }
+    }
+
     pub fn cards(self) -> Kingdom {
         match self {
-            KingdomSet::FirstGame => &[
+            KingdomSet::FirstGame => [
                 CardKind::Cellar,
                 CardKind::Moat,
                 CardKind::Village,
@@ -23,6 +61,153 @@ impl KingdomSet {
                 CardKind::Market,
                 CardKind::Mine,
             ],
+            KingdomSet::SizeDistortion => [
+                CardKind::Chapel,
+                CardKind::Workshop,
+                CardKind::Bureaucrat,
+                CardKind::Gardens,
+                CardKind::ThroneRoom,
+                CardKind::Bandit,
+                CardKind::Festival,
+                CardKind::Sentry,
+                CardKind::Witch,
+                CardKind::Artisan,
+            ],
+            KingdomSet::DeckTop => [
+                CardKind::Harbinger,
+                CardKind::Vassal,
+                CardKind::Village,
+                CardKind::Bureaucrat,
+                CardKind::Moneylender,
+                CardKind::CouncilRoom,
+                CardKind::Festival,
+                CardKind::Laboratory,
+                CardKind::Sentry,
+                CardKind::Artisan,
+            ],
+            KingdomSet::SleightOfHand => [
+                CardKind::Cellar,
+                CardKind::Harbinger,
+                CardKind::Gardens,
+                CardKind::Militia,
+                CardKind::Poacher,
+                CardKind::Smithy,
+                CardKind::ThroneRoom,
+                CardKind::CouncilRoom,
+                CardKind::Festival,
+                CardKind::Library,
+            ],
+            KingdomSet::Improvements => [
+                CardKind::Cellar,
+                CardKind::Moat,
+                CardKind::Merchant,
+                CardKind::Moneylender,
+                CardKind::Poacher,
+                CardKind::Remodel,
+                CardKind::Market,
+                CardKind::Mine,
+                CardKind::Witch,
+                CardKind::Artisan,
+            ],
+            KingdomSet::SilverAndGold => [
+                CardKind::Harbinger,
+                CardKind::Merchant,
+                CardKind::Vassal,
+                CardKind::Bureaucrat,
+                CardKind::Moneylender,
+                CardKind::ThroneRoom,
+                CardKind::Bandit,
+                CardKind::Laboratory,
+                CardKind::Mine,
+                CardKind::Chapel,
+            ],
+        }
+    }
+}
+
+/// Parses either the variant name, such as `SilverAndGold`, or the name
+/// printed in the rulebook ignoring case, such as `silver & gold`.
+impl FromStr for KingdomSet {
+    type Err = serde_json::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(&format!("\"{}\"", s)).or_else(|e| {
+            KingdomSet::ALL
+                .iter()
+                .copied()
+                .find(|set| set.name().eq_ignore_ascii_case(s))
+                .ok_or(e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_kingdom_set() {
+        assert_eq!(
+            "FirstGame".parse::<KingdomSet>().unwrap(),
+            KingdomSet::FirstGame
+        );
+        assert_eq!(
+            "SilverAndGold".parse::<KingdomSet>().unwrap(),
+            KingdomSet::SilverAndGold
+        );
+        assert!("LastGame".parse::<KingdomSet>().is_err());
+    }
+
+    #[test]
+    fn parse_rulebook_name() {
+        assert_eq!(
+            "Sleight of Hand".parse::<KingdomSet>().unwrap(),
+            KingdomSet::SleightOfHand
+        );
+        assert_eq!(
+            "silver & gold".parse::<KingdomSet>().unwrap(),
+            KingdomSet::SilverAndGold
+        );
+        assert!("Silver and Gold".parse::<KingdomSet>().is_err());
+    }
+
+    #[test]
+    fn every_kingdom_set_parses_from_its_name() {
+        for &set in KingdomSet::ALL {
+            assert_eq!(set.name().parse::<KingdomSet>().unwrap(), set);
+            assert_eq!(
+                set.name().to_uppercase().parse::<KingdomSet>().unwrap(),
+                set
+            );
+        }
+    }
+
+    #[test]
+    fn every_kingdom_set_parses_from_its_variant_name() {
+        for &set in KingdomSet::ALL {
+            assert_eq!(format!("{:?}", set).parse::<KingdomSet>().unwrap(), set);
+        }
+    }
+
+    #[test]
+    fn kingdom_sets_only_contain_kingdom_cards() {
+        for &set in KingdomSet::ALL {
+            let cards = set.cards();
+
+            for (i, card) in cards.iter().enumerate() {
+                assert!(
+                    CardKind::KINGDOM.contains(card),
+                    "{} contains {:?}",
+                    set.name(),
+                    card
+                );
+                assert!(
+                    !cards[..i].contains(card),
+                    "{} contains {:?} twice",
+                    set.name(),
+                    card
+                );
+            }
         }
     }
 }
@@ -0,0 +1,305 @@
+use super::Kingdom;
+use crate::dominion::CardKind;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+
+/// Costs that a kingdom with a cost spread has at least one card of.
+const SPREAD_COSTS: &[u8] = &[2, 3, 4, 5];
+
+/// Number of times the cards are redrawn before the constraints are
+/// considered unsatisfiable. Required cards are drawn first, which can use up
+/// room needed by a later requirement.
+const MAX_ATTEMPTS: usize = 100;
+
+/// Draws 10 random kingdom cards that satisfy a set of constraints.
+#[derive(Clone, Debug, Default)]
+pub struct Randomizer {
+    /// Leave out every Attack card.
+    pub exclude_attacks: bool,
+    /// Include a card that gives +Buy.
+    pub require_buy: bool,
+    /// Include a village, a card that gives +2 Actions.
+    pub require_village: bool,
+    /// Include a card at each cost from $2 to $5.
+    pub cost_spread: bool,
+    /// Cards that are always in the kingdom.
+    pub include: Vec<CardKind>,
+    /// Cards that are never in the kingdom.
+    pub exclude: Vec<CardKind>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum KingdomError {
+    /// An included card is not a kingdom card, is listed twice or is also
+    /// excluded.
+    InvalidCard(CardKind),
+    /// No kingdom satisfies every constraint.
+    Unsatisfiable,
+}
+
+type Requirement = Box<dyn Fn(CardKind) -> bool>;
+
+impl Randomizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a kingdom drawn at random, ordered by cost. The same seed
+    /// always draws the same kingdom.
+    ///
+    /// # Errors
+    ///
+    /// If an included card can't be in the kingdom, or if no kingdom satisfies
+    /// every constraint, then an error is returned.
+    pub fn generate(&self, seed: u64) -> Result<Kingdom, KingdomError> {
+        for (i, &card) in self.include.iter().enumerate() {
+            if !CardKind::KINGDOM.contains(&card)
+                || !self.allows(card)
+                || self.include[..i].contains(&card)
+            {
+                return Err(KingdomError::InvalidCard(card));
+            }
+        }
+
+        let mut rng = Pcg64Mcg::seed_from_u64(seed);
+        let mut cards = (0..MAX_ATTEMPTS)
+            .filter_map(|_| self.draw(&mut rng))
+            .next()
+            .ok_or(KingdomError::Unsatisfiable)?;
+
+        cards.sort_by_key(|card| (card.cost(), card.name()));
+
+        let mut kingdom = [CardKind::Copper; 10];
+        kingdom.copy_from_slice(&cards);
+
+        Ok(kingdom)
+    }
+
+    fn allows(&self, card: CardKind) -> bool {
+        !self.exclude.contains(&card) && !(self.exclude_attacks && card.is_attack())
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        let mut requirements: Vec<Requirement> = Vec::new();
+
+        if self.require_village {
+            requirements.push(Box::new(|card| {
+                card.resources()
+                    .map_or(false, |resources| resources.actions >= 2)
+            }));
+        }
+        if self.require_buy {
+            requirements.push(Box::new(|card| {
+                card.resources()
+                    .map_or(false, |resources| resources.buys > 0)
+            }));
+        }
+        if self.cost_spread {
+            for &cost in SPREAD_COSTS {
+                requirements.push(Box::new(move |card| card.cost() == cost));
+            }
+        }
+
+        requirements
+    }
+
+    /// Draws the included cards, then a card for each requirement that is not
+    /// yet met, then random cards. Returns `None` if the constraints were not
+    /// met.
+    fn draw(&self, rng: &mut Pcg64Mcg) -> Option<Vec<CardKind>> {
+        let mut cards = self.include.clone();
+        let mut pool: Vec<_> = CardKind::KINGDOM
+            .iter()
+            .cloned()
+            .filter(|&card| self.allows(card) && !cards.contains(&card))
+            .collect();
+
+        pool.shuffle(rng);
+
+        for requirement in self.requirements() {
+            if !cards.iter().any(|&card| requirement(card)) {
+                let i = pool.iter().position(|&card| requirement(card))?;
+                cards.push(pool.remove(i));
+            }
+        }
+
+        let n = 10usize.checked_sub(cards.len())?;
+        if n > pool.len() {
+            return None;
+        }
+        cards.extend(pool.drain(..n));
+
+        Some(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::Arena;
+
+    fn is_village(card: &CardKind) -> bool {
+        card.resources().map_or(false, |r| r.actions >= 2)
+    }
+
+    fn has_buy(card: &CardKind) -> bool {
+        card.resources().map_or(false, |r| r.buys > 0)
+    }
+
+    #[test]
+    fn generate_distinct_kingdom_cards() {
+        for seed in 0..20 {
+            let kingdom = Randomizer::new().generate(seed).unwrap();
+
+            for (i, card) in kingdom.iter().enumerate() {
+                assert!(CardKind::KINGDOM.contains(card), "{:?}", card);
+                assert!(!kingdom[..i].contains(card), "{:?}", kingdom);
+            }
+        }
+    }
+
+    #[test]
+    fn generate_is_determined_by_seed() {
+        let randomizer = Randomizer::new();
+
+        assert_eq!(randomizer.generate(3), randomizer.generate(3));
+        assert!((0..10).any(|seed| randomizer.generate(seed) != randomizer.generate(3)));
+    }
+
+    #[test]
+    fn generate_without_attacks() {
+        let randomizer = Randomizer {
+            exclude_attacks: true,
+            ..Randomizer::default()
+        };
+
+        for seed in 0..20 {
+            let kingdom = randomizer.generate(seed).unwrap();
+            assert!(
+                !kingdom.iter().any(|card| card.is_attack()),
+                "{:?}",
+                kingdom
+            );
+        }
+    }
+
+    #[test]
+    fn generate_with_requirements() {
+        let randomizer = Randomizer {
+            require_buy: true,
+            require_village: true,
+            cost_spread: true,
+            ..Randomizer::default()
+        };
+
+        for seed in 0..20 {
+            let kingdom = randomizer.generate(seed).unwrap();
+
+            assert!(kingdom.iter().any(is_village), "{:?}", kingdom);
+            assert!(kingdom.iter().any(has_buy), "{:?}", kingdom);
+            for &cost in SPREAD_COSTS {
+                assert!(
+                    kingdom.iter().any(|card| card.cost() == cost),
+                    "{:?}",
+                    kingdom
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generate_with_included_and_excluded_cards() {
+        let randomizer = Randomizer {
+            include: vec![CardKind::Chapel, CardKind::Witch],
+            exclude: vec![CardKind::Village, CardKind::Smithy],
+            ..Randomizer::default()
+        };
+
+        for seed in 0..20 {
+            let kingdom = randomizer.generate(seed).unwrap();
+
+            assert!(kingdom.contains(&CardKind::Chapel));
+            assert!(kingdom.contains(&CardKind::Witch));
+            assert!(!kingdom.contains(&CardKind::Village));
+            assert!(!kingdom.contains(&CardKind::Smithy));
+        }
+    }
+
+    #[test]
+    fn generate_satisfies_requirements_with_little_room() {
+        // Only Festival is both a village and gives +Buy.
+        let randomizer = Randomizer {
+            require_buy: true,
+            require_village: true,
+            include: CardKind::KINGDOM
+                .iter()
+                .cloned()
+                .filter(|card| !is_village(card) && !has_buy(card))
+                .take(9)
+                .collect(),
+            ..Randomizer::default()
+        };
+
+        assert!(randomizer
+            .generate(0)
+            .unwrap()
+            .contains(&CardKind::Festival));
+    }
+
+    #[test]
+    fn generate_invalid_cards() {
+        let include = |cards: Vec<CardKind>| Randomizer {
+            include: cards,
+            exclude: vec![CardKind::Moat],
+            exclude_attacks: true,
+            ..Randomizer::default()
+        };
+
+        assert_eq!(
+            include(vec![CardKind::Copper]).generate(0),
+            Err(KingdomError::InvalidCard(CardKind::Copper))
+        );
+        assert_eq!(
+            include(vec![CardKind::Moat]).generate(0),
+            Err(KingdomError::InvalidCard(CardKind::Moat))
+        );
+        assert_eq!(
+            include(vec![CardKind::Witch]).generate(0),
+            Err(KingdomError::InvalidCard(CardKind::Witch))
+        );
+        assert_eq!(
+            include(vec![CardKind::Cellar, CardKind::Cellar]).generate(0),
+            Err(KingdomError::InvalidCard(CardKind::Cellar))
+        );
+    }
+
+    #[test]
+    fn generate_unsatisfiable() {
+        let too_many = Randomizer {
+            include: CardKind::KINGDOM[..11].to_vec(),
+            ..Randomizer::default()
+        };
+        let no_villages = Randomizer {
+            require_village: true,
+            exclude: vec![CardKind::Village, CardKind::Festival],
+            ..Randomizer::default()
+        };
+        let too_few = Randomizer {
+            exclude: CardKind::KINGDOM[..20].to_vec(),
+            ..Randomizer::default()
+        };
+
+        assert_eq!(too_many.generate(0), Err(KingdomError::Unsatisfiable));
+        assert_eq!(no_villages.generate(0), Err(KingdomError::Unsatisfiable));
+        assert_eq!(too_few.generate(0), Err(KingdomError::Unsatisfiable));
+    }
+
+    #[test]
+    fn arena_with_random_kingdom() {
+        let kingdom = Randomizer::new().generate(1).unwrap();
+        let arena = Arena::with_kingdom(&kingdom, 2, Pcg64Mcg::seed_from_u64(1)).unwrap();
+
+        assert!(arena.kingdom().eq(kingdom.iter()));
+    }
+}
@@ -51,9 +51,10 @@ pub trait Location {
     fn move_all_cards(&mut self, other: &mut impl Location, cards: &[CardKind]) -> Result<()>;
 }
 
+use serde::{Deserialize, Serialize};
 use shrinkwraprs::Shrinkwrap;
 
-#[derive(Debug, Default, PartialEq, Shrinkwrap)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Shrinkwrap)]
 #[shrinkwrap(mutable)]
 pub struct CardVec(pub Vec<CardKind>);
 
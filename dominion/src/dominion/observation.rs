@@ -0,0 +1,38 @@
+use crate::dominion::decision::Decision;
+use crate::dominion::turn::Turn;
+use crate::dominion::CardKind;
+
+/// What a single player is able to see of a game. Cards that are hidden from
+/// the observer, such as other players' hands or the order of any draw pile,
+/// are only given as counts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Observation {
+    /// Id of the observing player.
+    pub player_id: usize,
+    pub current_player_id: usize,
+    pub turn: Turn,
+    /// The observer's own hand.
+    pub hand: Vec<CardKind>,
+    /// Public information of every player, ordered by player id.
+    pub players: Vec<PlayerObservation>,
+    /// Number of cards remaining in each supply pile.
+    pub supply: Vec<(CardKind, usize)>,
+    pub trash: Vec<CardKind>,
+    /// Decision that must be made before the game can continue, if any.
+    pub pending_decision: Option<Decision>,
+}
+
+/// Information of a single player that is visible to every other player.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerObservation {
+    pub hand_size: usize,
+    pub draw_pile_size: usize,
+    pub discard_pile_size: usize,
+    /// Top card of the discard pile. Only the top card is face up.
+    pub discard_top: Option<CardKind>,
+    pub play_zone: Vec<CardKind>,
+    /// Cards that are revealed or set aside. These are only given to the
+    /// owning player as some effects, such as Sentry, look at cards privately.
+    pub stage: Option<Vec<CardKind>>,
+    pub stage_size: usize,
+}
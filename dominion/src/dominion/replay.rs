@@ -0,0 +1,274 @@
+use crate::dominion::config::{ConfigError, GameConfig};
+use crate::dominion::event::GameEvent;
+use crate::dominion::score::Score;
+use crate::dominion::types::{Move, Result};
+use crate::dominion::Arena;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// A recorded game that can be re-executed to reproduce its end state.
+///
+/// Every move made by a player is recorded, including moves that were
+/// rejected, so that a replay makes exactly the same calls on the arena.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Replay {
+    pub config: GameConfig,
+    /// Moves in the order they were made, tagged with the player that made
+    /// them.
+    pub commands: Vec<(usize, Move)>,
+    /// State of the game after the last recorded move, as of the last call
+    /// to `finish`.
+    pub end_state: EndState,
+}
+
+/// A digest of the state of a game, small enough to store with a replay even
+/// though the event log includes every player's private draws.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EndState {
+    pub scores: Vec<Score>,
+    /// Number of events emitted by the arena.
+    pub num_events: usize,
+    /// FNV-1a hash of every event emitted by the arena, in order.
+    pub events_hash: u64,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ReplayError {
+    /// The recorded setup is not valid.
+    InvalidConfig(ConfigError),
+    /// Re-executing the commands resulted in a different end state.
+    Mismatch {
+        expected: EndState,
+        actual: EndState,
+    },
+}
+
+impl EndState {
+    fn new(arena: &Arena) -> Self {
+        Self {
+            scores: arena.scores(),
+            num_events: arena.events().len(),
+            events_hash: hash_events(arena.events()),
+        }
+    }
+}
+
+/// Hashes the JSON of each event, which unlike `std::hash::Hash` does not
+/// depend on the platform or the version of Rust.
+fn hash_events(events: &[GameEvent]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    events
+        .iter()
+        .flat_map(|event| serde_json::to_vec(event).unwrap())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+}
+
+impl Replay {
+    /// Starts a recording of a game with the given setup.
+    ///
+    /// # Errors
+    ///
+    /// If `config` is not valid, then an error is returned.
+    pub fn new(config: GameConfig) -> std::result::Result<Self, ConfigError> {
+        let mut replay = Self {
+            config,
+            commands: Vec::new(),
+            end_state: EndState {
+                scores: Vec::new(),
+                num_events: 0,
+                events_hash: 0,
+            },
+        };
+        replay.end_state = EndState::new(&replay.arena()?);
+
+        Ok(replay)
+    }
+
+    /// Returns a new arena with the recorded setup and no moves made.
+    ///
+    /// # Errors
+    ///
+    /// If the recorded setup is not valid, then an error is returned.
+    pub fn arena(&self) -> std::result::Result<Arena, ConfigError> {
+        Arena::with_config(&self.config)
+    }
+
+    /// Makes a move on `arena` and records it. The move is recorded even if it
+    /// is rejected.
+    pub fn record(&mut self, arena: &mut Arena, player_id: usize, mv: Move) -> Result<()> {
+        let result = arena.make_move(player_id, &mv);
+
+        self.commands.push((player_id, mv));
+
+        result
+    }
+
+    /// Records the state of `arena` as the end state, once the last move has
+    /// been recorded.
+    pub fn finish(&mut self, arena: &Arena) {
+        self.end_state = EndState::new(arena);
+    }
+
+    /// Re-executes the recorded moves on a new arena and checks that the game
+    /// ends in the recorded state.
+    pub fn run(&self) -> std::result::Result<Arena, ReplayError> {
+        let mut arena = self.arena().map_err(ReplayError::InvalidConfig)?;
+
+        for (player_id, mv) in &self.commands {
+            // Rejected moves were recorded too and are expected to fail again.
+            let _ = arena.make_move(*player_id, mv);
+        }
+
+        let actual = EndState::new(&arena);
+        if actual == self.end_state {
+            Ok(arena)
+        } else {
+            Err(ReplayError::Mismatch {
+                expected: self.end_state.clone(),
+                actual,
+            })
+        }
+    }
+
+    pub fn read<R: Read>(reader: R) -> serde_json::Result<Self> {
+        serde_json::from_reader(reader)
+    }
+
+    pub fn write<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::{CardKind, KingdomSet};
+
+    fn new_replay() -> Replay {
+        Replay::new(GameConfig::new(KingdomSet::FirstGame.cards(), 2, 1).unwrap()).unwrap()
+    }
+
+    fn play_turns(replay: &mut Replay, arena: &mut Arena, turns: usize) {
+        for _ in 0..turns {
+            let player_id = arena.current_player_id();
+
+            for mv in &[
+                Move::EndPhase,
+                Move::Play(CardKind::Copper),
+                Move::Play(CardKind::Copper),
+                Move::Play(CardKind::Copper),
+                Move::Buy(CardKind::Silver),
+                Move::EndPhase,
+            ] {
+                let _ = replay.record(arena, player_id, mv.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn run_reproduces_recorded_game() {
+        let mut replay = new_replay();
+        let mut arena = replay.arena().unwrap();
+
+        play_turns(&mut replay, &mut arena, 10);
+        replay.finish(&arena);
+
+        let replayed = replay.run().unwrap();
+        assert_eq!(replayed.events(), arena.events());
+    }
+
+    #[test]
+    fn run_detects_different_end_state() {
+        let mut replay = new_replay();
+        let mut arena = replay.arena().unwrap();
+
+        play_turns(&mut replay, &mut arena, 10);
+        replay.finish(&arena);
+        replay.commands.pop();
+
+        match replay.run() {
+            Err(ReplayError::Mismatch { expected, actual }) => assert_ne!(expected, actual),
+            Ok(_) => panic!("replay with a missing command matched"),
+            Err(e) => panic!("replay failed: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn run_detects_different_moves_with_same_scores() {
+        let mut replay = new_replay();
+        let mut arena = replay.arena().unwrap();
+
+        // Play turns until a Silver is bought.
+        let bought = loop {
+            let player_id = arena.current_player_id();
+
+            for mv in &[
+                Move::EndPhase,
+                Move::Play(CardKind::Copper),
+                Move::Play(CardKind::Copper),
+                Move::Play(CardKind::Copper),
+            ] {
+                let _ = replay.record(&mut arena, player_id, mv.clone());
+            }
+            let result = replay.record(&mut arena, player_id, Move::Buy(CardKind::Silver));
+            let index = replay.commands.len() - 1;
+            let _ = replay.record(&mut arena, player_id, Move::EndPhase);
+
+            if result.is_ok() {
+                break index;
+            }
+        };
+        play_turns(&mut replay, &mut arena, 4);
+        replay.finish(&arena);
+
+        // Buying a Village instead emits as many events and does not change
+        // the victory points.
+        replay.commands[bought].1 = Move::Buy(CardKind::Village);
+
+        match replay.run() {
+            Err(ReplayError::Mismatch { expected, actual }) => {
+                assert_eq!(expected.scores, actual.scores);
+                assert_eq!(expected.num_events, actual.num_events);
+                assert_ne!(expected.events_hash, actual.events_hash);
+            }
+            Ok(_) => panic!("replay with a different move matched"),
+            Err(e) => panic!("replay failed: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn run_rejects_invalid_config() {
+        let mut replay = new_replay();
+        replay.config.players.pop();
+
+        assert_eq!(
+            replay.run().map(|_| ()),
+            Err(ReplayError::InvalidConfig(ConfigError::InvalidPlayerCount(
+                1
+            )))
+        );
+    }
+
+    #[test]
+    fn write_and_read() {
+        let mut replay = new_replay();
+        let mut arena = replay.arena().unwrap();
+
+        play_turns(&mut replay, &mut arena, 2);
+        let _ = replay.record(
+            &mut arena,
+            1,
+            Move::Select(vec![CardKind::Copper, CardKind::Estate]),
+        );
+        replay.finish(&arena);
+
+        let mut buffer = Vec::new();
+        replay.write(&mut buffer).unwrap();
+
+        assert_eq!(Replay::read(buffer.as_slice()).unwrap(), replay);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::dominion::config::{PlayerConfig, Seat};
+use crate::dominion::Arena;
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// A game in progress saved together with its players, so that it can be
+/// resumed with the same names and seats.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SavedGame {
+    /// Players ordered by player id.
+    pub players: Vec<PlayerConfig>,
+    pub arena: Arena,
+}
+
+impl SavedGame {
+    /// Reads a game previously written with `write`.
+    ///
+    /// # Errors
+    ///
+    /// If the game can't be read or is not consistent, if the number of
+    /// players differs from the number of players in the arena, or if a bot
+    /// is not a built-in agent, then an error is returned.
+    pub fn read<R: Read>(reader: R) -> serde_json::Result<Self> {
+        let game: Self = serde_json::from_reader(reader)?;
+        game.arena.validate()?;
+
+        if game.players.len() != game.arena.scores().len() {
+            return Err(serde_json::Error::custom("wrong number of players"));
+        }
+        for player in &game.players {
+            if let (Seat::Bot(name), None) = (&player.seat, player.seat.agent()) {
+                return Err(serde_json::Error::custom(format!("unknown agent {}", name)));
+            }
+        }
+
+        Ok(game)
+    }
+
+    pub fn write<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dominion::KingdomSet;
+
+    fn players() -> Vec<PlayerConfig> {
+        vec![
+            PlayerConfig {
+                name: "Alice".to_string(),
+                seat: Seat::Human,
+            },
+            PlayerConfig {
+                name: "Bob".to_string(),
+                seat: Seat::Bot("big-money".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn write_and_read() {
+        let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
+        arena.end_turn_phase().unwrap();
+
+        let game = SavedGame {
+            players: players(),
+            arena,
+        };
+        let mut buffer = Vec::new();
+        game.write(&mut buffer).unwrap();
+
+        let loaded = SavedGame::read(buffer.as_slice()).unwrap();
+        assert_eq!(loaded.players, game.players);
+        assert_eq!(loaded.arena.turn(), game.arena.turn());
+        assert_eq!(loaded.arena.events(), game.arena.events());
+    }
+
+    #[test]
+    fn read_with_wrong_number_of_players() {
+        let game = SavedGame {
+            players: players(),
+            arena: Arena::with_seed(KingdomSet::FirstGame, 3, 1),
+        };
+        let mut buffer = Vec::new();
+        game.write(&mut buffer).unwrap();
+
+        assert!(SavedGame::read(buffer.as_slice()).is_err());
+    }
+
+    #[test]
+    fn read_with_invalid_current_player() {
+        let game = SavedGame {
+            players: players(),
+            arena: Arena::with_seed(KingdomSet::FirstGame, 2, 1),
+        };
+        let mut buffer = Vec::new();
+        game.write(&mut buffer).unwrap();
+
+        let json = String::from_utf8(buffer)
+            .unwrap()
+            .replace("\"current_player_id\":0", "\"current_player_id\":5");
+
+        assert!(SavedGame::read(json.as_bytes()).is_err());
+    }
+}
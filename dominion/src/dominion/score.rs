@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Score {
+    pub player_id: usize,
+    pub victory_points: i32,
+    pub turns: u32,
+}
+
+impl Score {
+    /// Returns `true` if `self` places ahead of `other`. Ties in victory points
+    /// are broken in favour of the player that has taken fewer turns.
+    fn beats(&self, other: &Self) -> bool {
+        (self.victory_points > other.victory_points)
+            || ((self.victory_points == other.victory_points) && (self.turns < other.turns))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Standing {
+    /// Place of the player, starting from 1. Players that remain tied share
+    /// the same place.
+    pub place: usize,
+    pub score: Score,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResult {
+    /// Standings ordered from first to last place.
+    pub standings: Vec<Standing>,
+}
+
+impl GameResult {
+    pub fn new(scores: &[Score]) -> Self {
+        let mut standings: Vec<_> = scores
+            .iter()
+            .map(|&score| Standing {
+                place: 1 + scores.iter().filter(|other| other.beats(&score)).count(),
+                score,
+            })
+            .collect();
+
+        standings.sort_by_key(|standing| (standing.place, standing.score.player_id));
+
+        Self { standings }
+    }
+
+    pub fn winners(&self) -> impl Iterator<Item = usize> + '_ {
+        self.standings
+            .iter()
+            .filter(|standing| standing.place == 1)
+            .map(|standing| standing.score.player_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(player_id: usize, victory_points: i32, turns: u32) -> Score {
+        Score {
+            player_id,
+            victory_points,
+            turns,
+        }
+    }
+
+    #[test]
+    fn single_winner() {
+        let result = GameResult::new(&[score(0, 10, 15), score(1, 20, 15), score(2, 5, 14)]);
+
+        assert_eq!(result.winners().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            result
+                .standings
+                .iter()
+                .map(|standing| (standing.place, standing.score.player_id))
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (2, 0), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn tie_broken_by_fewer_turns() {
+        let result = GameResult::new(&[score(0, 20, 15), score(1, 20, 14)]);
+
+        assert_eq!(result.winners().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(result.standings[1].place, 2);
+    }
+
+    #[test]
+    fn shared_victory() {
+        let result = GameResult::new(&[score(0, 20, 15), score(1, 20, 15), score(2, 3, 15)]);
+
+        assert_eq!(result.winners().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(result.standings[2].place, 3);
+    }
+}
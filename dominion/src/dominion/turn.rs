@@ -1,6 +1,7 @@
 use crate::dominion::{Error, Result};
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Turn {
     Action(ActionPhase),
     Buy(BuyPhase),
@@ -36,7 +37,7 @@ impl Turn {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ActionPhase {
     pub remaining_actions: u8,
     pub remaining_buys: u8,
@@ -52,7 +53,7 @@ impl ActionPhase {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct BuyPhase {
     pub remaining_buys: u8,
     pub remaining_copper: u8,
@@ -1,8 +1,11 @@
 use crate::dominion::CardKind;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Eq, PartialEq)]
+#[allow(dead_code)]
 pub enum Error {
     InvalidPlayerId,
+    InvalidPlayerCount,
     InvalidCard,
     InvalidIndex,
     WrongTurnPhase,
@@ -11,6 +14,9 @@ pub enum Error {
     NoMoreCards,
     NotEnoughCopper,
     UnresolvedActionEffect(&'static str),
+    NothingToUndo,
+    IllegalMove,
+    TooManyTurns,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -28,3 +34,26 @@ pub enum Location {
     Supply,
     Trash,
 }
+
+/// Read-only contents of a `Location` returned by `Arena::view`.
+#[derive(Debug, PartialEq)]
+pub enum LocationView<'a> {
+    /// Cards in the location, with the last card on top.
+    Cards(&'a [CardKind]),
+    /// Number of cards in a location whose contents are hidden.
+    Count(usize),
+    /// Number of cards remaining in each supply pile.
+    Supply(Vec<(CardKind, usize)>),
+}
+
+/// A single move that a player can make.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Move {
+    Play(CardKind),
+    Buy(CardKind),
+    EndPhase,
+    Select(Vec<CardKind>),
+    /// Reverts the previous move of the turn. This is never a legal move but
+    /// is recorded so that replays include undone moves.
+    Undo,
+}
@@ -2,8 +2,14 @@
 
 pub mod dominion;
 
+pub use crate::dominion::agent::{play_game, Agent, BigMoney, BigMoneySmithy, Mcts, PlayerView};
 pub use crate::dominion::arena::Arena;
 pub use crate::dominion::card::CardKind;
-pub use crate::dominion::kingdom::KingdomSet;
+pub use crate::dominion::config::{ConfigError, GameConfig, PileConfig, PlayerConfig, Seat};
+pub use crate::dominion::decision::{CardCount, CardFilter, Chooser, CostBound, Decision, Zone};
+pub use crate::dominion::event::GameEvent;
+pub use crate::dominion::kingdom::{Kingdom, KingdomError, KingdomSet, Randomizer};
+pub use crate::dominion::observation::{Observation, PlayerObservation};
+pub use crate::dominion::score::{GameResult, Score};
 pub use crate::dominion::turn::Turn;
-pub use crate::dominion::types::Location;
+pub use crate::dominion::types::{Location, LocationView, Move};
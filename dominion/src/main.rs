@@ -3,12 +3,137 @@
 
 mod dominion;
 
+use crate::dominion::agent::{self, PlayerView, AGENT_NAMES};
 use crate::dominion::command::{self, Command, ParseCommandError};
-use crate::dominion::{Arena, KingdomSet, Result};
+use crate::dominion::config::{GameConfig, PlayerConfig};
+use crate::dominion::replay::Replay;
+use crate::dominion::saved_game::SavedGame;
+use crate::dominion::types::Move;
+use crate::dominion::{Arena, Error, KingdomSet, Result};
+use std::fs::File;
 
 fn main() {
-    let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
 
+    match args.as_slice() {
+        ["load", path] => load(path),
+        ["replay", path] => replay(path),
+        options => new_game(options),
+    }
+}
+
+fn usage() -> ! {
+    println!(
+        "Usage:\n\
+         dominion [<option>...] [--record <file>] - play a game, optionally recording it to <file>\n\
+         dominion load <file> - resume a game saved with the save command\n\
+         dominion replay <file> - replay a recorded game and verify its end state\n\
+         \n\
+         Options, where lists are separated by commas:\n\
+         --players <n> - number of players, from 2 to 6\n\
+         --names <names> - name of each player\n\
+         --seats <seats> - human or one of {} for each player\n\
+         --kingdom <set> - one of {}\n\
+         --cards <cards> - the 10 kingdom cards\n\
+         --seed <seed> - seed of the shuffles",
+        AGENT_NAMES.join(", "),
+        KingdomSet::ALL
+            .iter()
+            .map(|set| format!("\"{}\"", set.name()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    std::process::exit(2);
+}
+
+fn replay(path: &str) {
+    let replay = File::open(path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| Replay::read(file).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            println!("Unable to read replay {}: {}", path, e);
+            std::process::exit(1);
+        });
+
+    println!(
+        "Replaying {} commands with seed {}",
+        replay.commands.len(),
+        replay.config.seed
+    );
+
+    match replay.run() {
+        Ok(arena) => {
+            println!("End state verified");
+            if arena.is_game_over() {
+                print_result(&arena, &replay.config.players);
+            }
+        }
+        Err(e) => {
+            println!("Replay diverged: {:?}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn load(path: &str) {
+    let game = File::open(path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| SavedGame::read(file).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            println!("Unable to load game {}: {}", path, e);
+            std::process::exit(1);
+        });
+
+    play(game.arena, &game.players, None);
+}
+
+fn new_game(options: &[&str]) {
+    let mut record_path = None;
+    let mut config_args = Vec::new();
+    let mut options = options.iter();
+
+    while let Some(&option) = options.next() {
+        if option == "--record" {
+            record_path = Some(*options.next().unwrap_or_else(|| usage()));
+        } else {
+            config_args.push(option.to_string());
+        }
+    }
+
+    let config = GameConfig::from_args(config_args.into_iter()).unwrap_or_else(|e| {
+        println!("Invalid game: {}\n", e);
+        usage();
+    });
+    // The config was validated while parsing the options.
+    let replay = Replay::new(config.clone()).unwrap();
+    let arena = replay.arena().unwrap();
+
+    println!("Using seed {}", config.seed);
+    play(
+        arena,
+        &config.players,
+        record_path.map(|path| (replay, path)),
+    );
+}
+
+/// Makes a move, recording it if `recorder` is set.
+fn make_move(
+    arena: &mut Arena,
+    recorder: &mut Option<(Replay, &str)>,
+    player_id: usize,
+    mv: Move,
+) -> Result<()> {
+    match recorder.as_mut() {
+        Some((replay, _)) => replay.record(arena, player_id, mv),
+        None => arena.make_move(player_id, &mv),
+    }
+}
+
+/// Runs the REPL until the game is over, asking bots for their moves. If
+/// `recorder` is set, every move is recorded and the replay is written to its
+/// path when the game is over.
+fn play(mut arena: Arena, players: &[PlayerConfig], mut recorder: Option<(Replay, &str)>) {
     {
         print!("Starting game with ");
         let mut iter = arena.kingdom();
@@ -19,56 +144,150 @@ fn main() {
         println!();
     }
 
-    while !arena.is_game_over() {
-        println!("\n{:?}\n", arena.turn());
+    let mut agents: Vec<_> = players.iter().map(|player| player.seat.agent()).collect();
+
+    while let Some((player_id, moves)) = agent::next_to_move(&arena) {
+        let name = &players[player_id].name;
 
-        let mut command = String::new();
-        std::io::stdin().read_line(&mut command).unwrap();
+        let result = match agents[player_id].as_mut() {
+            Some(agent) => {
+                let view = PlayerView::new(&arena, player_id).unwrap();
+                let mv = agent.choose_from(&view, &moves);
 
-        let result: Result<()> = command.parse().map_or_else(
-            |e| {
-                if let ParseCommandError::InvalidCommand = e {
-                    println!("{}", command::help());
-                } else {
-                    println!("Error {}", e);
+                println!("{} chose {:?}", name, mv);
+                match make_move(&mut arena, &mut recorder, player_id, mv) {
+                    // Playing an Action card or selecting cards can leave a
+                    // decision pending.
+                    Err(Error::UnresolvedActionEffect(_)) => Ok(()),
+                    result => result,
                 }
+            }
+            None => {
+                println!("\n{}: {:?}\n", name, arena.turn());
+                run_command(&mut arena, &mut recorder, players, player_id)
+            }
+        };
 
-                Ok(())
-            },
-            |command| {
-                // TODO: allow non-current player to select cards
-                let player_id = arena.current_player_id();
-
-                match command {
-                    Command::View(_location) => {
-                        //FIXME println!("{:?}", arena.view(location)?);
-                        println!("location disabled");
-                        println!()
-                    }
-                    Command::EndPhase => {
-                        arena.end_turn_phase()?;
-                        println!("Starting {:?}", arena.turn());
-                    }
-                    Command::PlayCard(card) => {
-                        arena.play_card(card)?;
-                        println!("Player {} played {:?}", player_id, card);
-                    }
-                    Command::BuyCard(card) => {
-                        arena.buy_card(card)?;
-                        println!("Player {} bought {:?}", player_id, card);
+        if let Err(e) = result {
+            println!("Error: {:?}", e);
+        }
+
+        if let Some(decision) = arena.pending_decision() {
+            println!(
+                "Waiting for {:?}: {}",
+                decision.chooser, decision.description
+            );
+        }
+    }
+
+    if let Some((mut replay, path)) = recorder {
+        replay.finish(&arena);
+
+        if let Err(e) = File::create(path)
+            .map_err(|e| e.to_string())
+            .and_then(|file| replay.write(file).map_err(|e| e.to_string()))
+        {
+            println!("Unable to write replay {}: {}", path, e);
+        }
+    }
+
+    print_result(&arena, players);
+}
+
+/// Reads a command from stdin and runs it for the human player `player_id`.
+fn run_command(
+    arena: &mut Arena,
+    recorder: &mut Option<(Replay, &str)>,
+    players: &[PlayerConfig],
+    player_id: usize,
+) -> Result<()> {
+    let name = &players[player_id].name;
+    let mut command = String::new();
+    std::io::stdin().read_line(&mut command).unwrap();
+
+    command.parse().map_or_else(
+        |e| {
+            if let ParseCommandError::InvalidCommand = e {
+                println!("{}", command::help());
+            } else {
+                println!("Error {}", e);
+            }
+
+            Ok(())
+        },
+        |command| {
+            let mut make_move =
+                |arena: &mut Arena, mv: Move| make_move(arena, recorder, player_id, mv);
+
+            match command {
+                Command::View(location) => {
+                    println!("{:?}", arena.view(location)?);
+                    println!()
+                }
+                Command::ListMoves => {
+                    for m in arena.legal_moves(player_id)? {
+                        println!("{:?}", m);
                     }
-                    Command::SelectCards(cards) => {
-                        arena.select_cards(player_id, &cards)?;
-                        println!("Player {} selected {:?}", player_id, &cards);
+                    println!()
+                }
+                Command::EndPhase => {
+                    make_move(arena, Move::EndPhase)?;
+                    println!("Starting {:?}", arena.turn());
+                }
+                Command::PlayCard(card) => {
+                    make_move(arena, Move::Play(card))?;
+                    println!("{} played {:?}", name, card);
+                }
+                Command::BuyCard(card) => {
+                    make_move(arena, Move::Buy(card))?;
+                    println!("{} bought {:?}", name, card);
+                }
+                Command::SelectCards(cards) => {
+                    make_move(arena, Move::Select(cards.clone()))?;
+                    println!("{} selected {:?}", name, &cards);
+                }
+                Command::Undo => {
+                    make_move(arena, Move::Undo)?;
+                    println!("{} undid the last move", name);
+                }
+                Command::Save(path) => {
+                    let game = SavedGame {
+                        players: players.to_vec(),
+                        arena: arena.clone(),
+                    };
+
+                    match File::create(&path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|file| game.write(file).map_err(|e| e.to_string()))
+                    {
+                        Ok(()) => println!("Saved game to {}", path),
+                        Err(e) => println!("Unable to save game {}: {}", path, e),
                     }
-                };
+                }
+            };
 
-                Ok(())
-            },
-        );
+            Ok(())
+        },
+    )
+}
 
-        if let Err(e) = result {
-            println!("Error: {:?}", e);
-        }
+fn print_result(arena: &Arena, players: &[PlayerConfig]) {
+    let result = arena.result().unwrap();
+
+    println!(
+        "Game over, winners: {:?}",
+        result
+            .winners()
+            .map(|player_id| &players[player_id].name)
+            .collect::<Vec<_>>()
+    );
+    for standing in &result.standings {
+        println!(
+            "{}. {} with {} victory points in {} turns",
+            standing.place,
+            players[standing.score.player_id].name,
+            standing.score.victory_points,
+            standing.score.turns
+        );
     }
 }
@@ -1,6 +1,6 @@
 extern crate dominion;
 
-use dominion::{Arena, CardKind, KingdomSet, Turn};
+use dominion::{Arena, CardKind, KingdomSet, Location, Turn};
 
 fn skip_turn(arena: &mut Arena, _: usize) {
     arena.end_turn_phase().unwrap();
@@ -24,7 +24,7 @@ fn big_money(arena: &mut Arena, player_id: usize) {
     println!(
         "p{} playing: {:?}",
         player_id,
-        "" //FIXME arena.view(Location::Play { player_id }).unwrap()
+        arena.view(Location::Play { player_id }).unwrap()
     );
 
     arena
@@ -47,7 +47,7 @@ fn big_money(arena: &mut Arena, player_id: usize) {
 
 #[test]
 fn play_big_money() {
-    let mut arena = Arena::new(KingdomSet::FirstGame, 2);
+    let mut arena = Arena::with_seed(KingdomSet::FirstGame, 2, 1);
     let mut turn_number = 0;
 
     while !arena.is_game_over() {
@@ -56,18 +56,21 @@ fn play_big_money() {
         big_money(&mut arena, 0);
         skip_turn(&mut arena, 1);
 
-        //let player_id = 0;
+        let player_id = 0;
         println!("turn {}", turn_number);
         println!(
             "p1 discard pile: {:?}",
-            "" // FIXME arena.view(Location::Discard { player_id }).unwrap()
+            arena.view(Location::Discard { player_id }).unwrap()
         );
         println!(
             "p1 hand: {:?}",
-            "" // FIXME arena.view(Location::Hand { player_id }).unwrap()
+            arena.view(Location::Hand { player_id }).unwrap()
         );
         println!();
 
         assert!(turn_number <= 50);
     }
+
+    let result = arena.result().unwrap();
+    assert_eq!(result.winners().collect::<Vec<_>>(), vec![0]);
 }